@@ -1,10 +1,10 @@
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{
     parse_quote, punctuated::Punctuated, AngleBracketedGenericArguments, Data,
-    DeriveInput, Field, Fields, GenericArgument, GenericParam, Generics, Meta, MetaList,
-    NestedMeta, ParenthesizedGenericArguments, PathArguments, Type, TypeGenerics,
-    TypePath, TypeReference, TypeSlice, Variant,
+    DeriveInput, Field, Fields, GenericArgument, GenericParam, Generics, Lit, Meta,
+    MetaList, MetaNameValue, NestedMeta, ParenthesizedGenericArguments, PathArguments,
+    Type, TypeGenerics, TypePath, TypeReference, TypeSlice, Variant,
 };
 
 enum StructKind {
@@ -35,7 +35,7 @@ pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
 
     let derives = if emit_deserialize {
         quote! {
-            #[derive(Clone, Debug, ::serde::Deserialize)]
+            #[derive(Clone, Debug, ::serde::Deserialize, ::serde::Serialize)]
         }
     } else {
         quote! {
@@ -141,11 +141,19 @@ fn strip_lifetime_attrs(mut f: Field) -> Field {
             if let Ok(Meta::List(MetaList { nested, .. })) = attr.parse_meta() {
                 let filtered = nested
                     .into_iter()
-                    .filter(|meta| {
-                        !matches!(
-                            meta,
-                            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("borrow")
-                        )
+                    .filter(|meta| match meta {
+                        NestedMeta::Meta(Meta::Path(p)) => !p.is_ident("borrow"),
+                        // The `Cow<str>` helpers only exist to borrow, the incoming
+                        // `String` deserializes without them.
+                        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                            path,
+                            lit: Lit::Str(with),
+                            ..
+                        })) if path.is_ident("deserialize_with") => !matches!(
+                            with.value().rsplit("::").next(),
+                            Some("deserialize_cow_str" | "deserialize_cow_str_opt")
+                        ),
+                        _ => true,
                     })
                     .collect::<Vec<NestedMeta>>();
                 *attr = parse_quote! { #[serde(#( #filtered, )*)]}
@@ -225,24 +233,32 @@ fn strip_lifetimes(field_type: &mut Type) -> syn::Result<bool> {
             // If a type has a generic lifetime parameter there must be an `Incoming`
             // variant of that type.
             if is_lifetime_generic {
+                let mut owned_cow = None;
                 if let Some(name) = path.segments.last_mut() {
                     if name.ident == "Cow" {
                         if let PathArguments::AngleBracketed(
                             AngleBracketedGenericArguments { args, .. },
                         ) = &name.arguments
                         {
-                            let must_be_str = args.iter().any(|arg| {
-                                if let GenericArgument::Type(Type::Path(path)) = arg {
-                                    path.path.is_ident("str")
-                                } else {
-                                    false
+                            // Cow<'a, str> -> String, Cow<'a, Path> -> PathBuf
+                            owned_cow = args.iter().find_map(|arg| match arg {
+                                GenericArgument::Type(Type::Path(ty))
+                                    if ty.path.is_ident("str") =>
+                                {
+                                    Some(parse_quote! { ::std::string::String })
                                 }
+                                GenericArgument::Type(Type::Path(ty))
+                                    if ty.path.is_ident("Path") =>
+                                {
+                                    Some(parse_quote! { ::std::path::PathBuf })
+                                }
+                                _ => None,
                             });
-                            if must_be_str {
-                                name.ident = Ident::new("String", name.ident.span());
-                                name.arguments = PathArguments::None;
-                            } else {
-                                return Err(syn::Error::new_spanned(field_type, ""));
+                            if owned_cow.is_none() {
+                                return Err(syn::Error::new_spanned(
+                                    field_type,
+                                    "only `Cow<str>` and `Cow<Path>` are supported",
+                                ));
                             }
                         }
                     } else {
@@ -250,6 +266,9 @@ fn strip_lifetimes(field_type: &mut Type) -> syn::Result<bool> {
                         name.ident = incoming_ty_ident;
                     }
                 }
+                if let Some(owned) = owned_cow {
+                    *path = owned;
+                }
             }
 
             has_lifetimes || is_lifetime_generic
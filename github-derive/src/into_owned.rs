@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Index, Member, Type};
+
+use crate::rest_api::check_for_lifetimes;
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let lifetime = match input.generics.lifetimes().next() {
+        Some(def) => &def.lifetime,
+        None => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "#[derive(IntoOwned)] requires a lifetime parameter",
+            ));
+        }
+    };
+    if input.generics.params.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[derive(IntoOwned)] only supports a single lifetime parameter",
+        ));
+    }
+
+    let body = match &input.data {
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "#[derive(IntoOwned)] does not support Union types",
+            ));
+        }
+        Data::Struct(s) => {
+            let (bindings, init) = expand_fields(&s.fields)?;
+            quote! {
+                let #ident #bindings = self;
+                #ident #init
+            }
+        }
+        Data::Enum(e) => {
+            let mut arms = vec![];
+            for var in &e.variants {
+                let var_ident = &var.ident;
+                let (bindings, init) = expand_fields(&var.fields)?;
+                arms.push(quote! {
+                    #ident::#var_ident #bindings => #ident::#var_ident #init,
+                });
+            }
+            quote! {
+                match self { #( #arms )* }
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl<#lifetime> ::gitty_hub::utils::IntoOwned for #ident<#lifetime> {
+            type Owned = #ident<'static>;
+
+            fn into_owned(self) -> Self::Owned { #body }
+        }
+    })
+}
+
+/// Returns the pattern that destructures `fields` and the expression that rebuilds
+/// them with every borrowed field converted.
+fn expand_fields(fields: &Fields) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut bindings = vec![];
+    let mut init = vec![];
+    for (idx, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(idx)),
+        };
+        let binding = format_ident!("__field{}", idx);
+        let value = convert(&field.ty, &binding)?;
+
+        bindings.push(quote! { #member: #binding });
+        init.push(quote! { #member: #value });
+    }
+
+    Ok(match fields {
+        Fields::Unit => (TokenStream::new(), TokenStream::new()),
+        _ => (quote! { { #( #bindings, )* } }, quote! { { #( #init, )* } }),
+    })
+}
+
+fn convert(ty: &Type, binding: &syn::Ident) -> syn::Result<TokenStream> {
+    Ok(if check_for_lifetimes(ty)? {
+        quote! { ::gitty_hub::utils::IntoOwned::into_owned(#binding) }
+    } else {
+        quote! { #binding }
+    })
+}
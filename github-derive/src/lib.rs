@@ -2,21 +2,34 @@ use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput, ItemEnum};
 
 mod incoming;
+mod into_owned;
 mod rest_api;
 mod string_enum;
 
+/// Generate the `Request` and `Response` types of a github REST endpoint.
 #[proc_macro]
 pub fn github_rest_api(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as rest_api::GithubInput);
     rest_api::expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
 }
 
+/// Generate an `IncomingT` for a type `T<'a>` where every borrowed field is owned.
 #[proc_macro_derive(Incoming, attributes(no_deserialize))]
 pub fn incoming_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     incoming::expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
 }
 
+/// Implement `gitty_hub::utils::IntoOwned` for a type with a single lifetime parameter.
+///
+/// Every field whose type contains a lifetime is converted with `IntoOwned`, all other
+/// fields are moved as is.
+#[proc_macro_derive(IntoOwned)]
+pub fn into_owned_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_owned::expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
 /// Shorthand for the derives `AsRefStr`, `FromString`, `DisplayAsRefStr`,
 /// `SerializeAsRefStr` and `DeserializeFromCowStr`.
 #[proc_macro_derive(StringEnum, attributes(github_enum))]
@@ -5,7 +5,7 @@ mod parse;
 mod request;
 mod response;
 
-pub(crate) use parse::GithubInput;
+pub(crate) use parse::{check_for_lifetimes, GithubInput};
 
 pub(crate) fn expand(api: GithubInput) -> syn::Result<TokenStream> {
     let request = request::expand_request(&api.request, &api.metadata)?;
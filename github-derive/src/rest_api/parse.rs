@@ -295,6 +295,9 @@ pub(crate) fn check_for_lifetimes(field_type: &Type) -> syn::Result<bool> {
             }
             has_lifetime
         }
+        // Types passed through a `macro_rules` `$ty:ty` fragment arrive wrapped in a
+        // group.
+        Type::Group(syn::TypeGroup { elem, .. }) => check_for_lifetimes(elem)?,
         _ => false,
     })
 }
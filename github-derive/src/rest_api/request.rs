@@ -211,8 +211,13 @@ pub(crate) fn expand_request(req: &Request, meta: &Metadata) -> syn::Result<Toke
         }
     };
 
+    let lifetime = if has_all_lifetimes {
+        quote! { <'a> }
+    } else {
+        TokenStream::new()
+    };
     let to_reqwest = quote! {
-        impl<'a> ::gitty_hub::GithubRequest for Request<'a> {
+        impl #lifetime ::gitty_hub::GithubRequest for Request #lifetime {
             #metadata
             type Response = Response;
             fn to_request(
@@ -246,11 +251,6 @@ pub(crate) fn expand_request(req: &Request, meta: &Metadata) -> syn::Result<Toke
         }
     };
 
-    let lifetime = if has_all_lifetimes {
-        quote! { <'a> }
-    } else {
-        TokenStream::new()
-    };
     let name = fields.iter().map(|f| &f.name);
     let ty = fields.iter().map(|f| &f.ty);
     let attr = req.req_fields.iter().map(|f| {
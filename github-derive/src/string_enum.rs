@@ -105,8 +105,19 @@ pub fn expand_enum_from_string(input: &ItemEnum) -> syn::Result<TokenStream> {
     // repetition
     let branches = branches.iter().flatten();
 
-    // TODO: this can error reflect that, I don't love the _Custom(String) variant
-    // but maybe that is the best??
+    // Strings github adds after this enum was written are kept in `_Custom`.
+    let custom = input
+        .variants
+        .iter()
+        .find(|v| v.ident == "_Custom" && matches!(v.fields, Fields::Unnamed(_)))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                enum_name,
+                "StringEnum requires a `_Custom(String)` variant for unknown values",
+            )
+        })?;
+    let custom_name = &custom.ident;
+
     Ok(quote! {
         impl<T> ::std::convert::From<T> for #enum_name
         where
@@ -116,7 +127,7 @@ pub fn expand_enum_from_string(input: &ItemEnum) -> syn::Result<TokenStream> {
             fn from(s: T) -> Self {
                 match s.as_ref() {
                     #( #branches, )*
-                    s => unreachable!("github changed a stringly typed enum {}", s),
+                    _ => #enum_name :: #custom_name(s.into()),
                 }
             }
         }
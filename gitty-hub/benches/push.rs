@@ -0,0 +1,44 @@
+//! Parsing push events the size of a push to a busy monorepo.
+//!
+//! The commits of `real_push.json` are repeated until the push has the wanted number
+//! of commits, each commit lists around 15 added, removed or modified files.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput,
+};
+use gitty_hub::api::{webhooks::push::PushEvent, GitHubEvent};
+use serde_json::Value;
+
+/// Builds a push event with `commits` commits from `real_push.json`.
+fn scaled_push(commits: usize) -> String {
+    let mut push: Value =
+        serde_json::from_str(include_str!("../test_json/real_push.json")).unwrap();
+    let real = push["commits"].as_array().unwrap().clone();
+    push["commits"] = real.into_iter().cycle().take(commits).collect::<Vec<_>>().into();
+    serde_json::to_string(&push).unwrap()
+}
+
+fn push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push");
+    for commits in [4, 100, 500, 1000, 2000] {
+        let json = scaled_push(commits);
+        group.throughput(Throughput::Bytes(json.len() as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("from_raw", commits),
+            &json,
+            |b, json| b.iter(|| GitHubEvent::from_raw("push", black_box(json)).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("serde_json", commits),
+            &json,
+            |b, json| {
+                b.iter(|| serde_json::from_str::<PushEvent<'_>>(black_box(json)).unwrap())
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, push);
+criterion_main!(benches);
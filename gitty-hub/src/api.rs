@@ -1,17 +1,21 @@
+pub mod graphql;
 pub mod rest;
 pub mod webhooks;
 
 pub use webhooks::{
     common::{
-        datetime, datetime_opt, default_null, true_fn, AccessPermissions, App,
-        AuthorAssociation, Base, Branch, Changes, CheckStatus, Comment, Commit,
-        CommitInner, CommitTree, Committer, ConclusionStatus, Dt, EventKind, FileStatus,
-        Head, IncomingComment, IncomingCommit, IncomingIssue, IncomingPullRequest,
+        datetime, datetime_opt, default_null, null_default, true_fn, AccessPermissions,
+        AlertSeverity, App, AuthorAssociation, Base, Body, Branch, BranchRef, Changes,
+        CheckStatus, Comment, CommentChanges, Commit, CommitInner, CommitTree, Committer,
+        ConclusionStatus, Deployment, DeploymentState, DeploymentStatus, Diff, Dt,
+        EventKind, FieldDiff, FileStatus, Head, IncomingComment, IncomingCommit,
+        IncomingDeployment, IncomingDeploymentStatus, IncomingIssue, IncomingPullRequest,
         IncomingRepository, IncomingTeam, IncomingUser, IncomingWorkflow,
         IncomingWorkflowRun, Installation, Issue, IssueState, Label, Links, LockReason,
-        MergeStateStatus, Milestone, Org, Permissions, Plan, PullRequest,
-        RepoCreationType, RepoPermission, RepoSelection, Repository, ShortUser,
-        SimpleCommit, Team, Type, UrlMap, User, Verification, WorkflowEvent,
+        MergeStateStatus, Milestone, Org, Permissions, Plan, PullRequest, PullRequestRef,
+        RepoCreationType, RepoPermission, RepoRef, RepoSelection, Repository, ShortUser,
+        SimpleCommit, SimpleOrg, SimpleRepository, Team, Timestamp, Type, UrlMap, User,
+        Verification, Workflow, WorkflowEvent, WorkflowRun,
     },
-    GitHubEvent,
+    GitHubEvent, WebhookDelivery, WebhookError, WebhookPayload,
 };
@@ -0,0 +1,98 @@
+use reqwest::{header::CONTENT_LENGTH, Method};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{from_status, Error, GithubClient, GithubFailure, BASE_URL};
+
+pub mod issue;
+
+/// The path of Github's single GraphQL endpoint, every query and mutation is sent
+/// here.
+///
+/// The GraphQL API is only used where the REST API has no equivalent.
+pub const GRAPHQL_PATH: &str = "/graphql";
+
+/// An error reported by the GraphQL API.
+///
+/// GraphQL requests answer with a status of 200 even when they fail, the reasons are
+/// in the `errors` list of the response.
+#[derive(Clone, Debug, Deserialize)]
+pub struct GraphQlError {
+    /// A description of what went wrong.
+    pub message: String,
+
+    /// The kind of error ie. `NOT_FOUND`.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+}
+
+#[derive(Serialize)]
+struct GraphQlBody<'a, V> {
+    query: &'a str,
+    variables: V,
+}
+
+#[derive(Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+/// Build the POST request for a GraphQL `query` and its `variables`.
+pub(crate) fn graphql_request<V: Serialize>(
+    github: &GithubClient,
+    query: &str,
+    variables: V,
+) -> Result<reqwest::Request, Error> {
+    let json = serde_json::to_string(&GraphQlBody { query, variables })?;
+
+    let request = github
+        .request_builder(Method::POST, &format!("{}{}", BASE_URL, GRAPHQL_PATH))
+        .header(CONTENT_LENGTH, json.len())
+        .body(json);
+
+    // The GraphQL API can not be used anonymously.
+    let request = if let Some(tkn) = github.tkn.as_ref() {
+        request.bearer_auth(tkn)
+    } else {
+        request
+    };
+
+    request.build().map_err(Into::into)
+}
+
+/// Read the `data` out of a GraphQL response, any reported errors are returned as
+/// [`GithubFailure::GraphQl`].
+pub(crate) async fn from_graphql_response<T: DeserializeOwned>(
+    resp: reqwest::Response,
+) -> Result<T, Error> {
+    from_status(resp.status())?;
+
+    from_graphql_json(&resp.text().await?)
+}
+
+pub(crate) fn from_graphql_json<T: DeserializeOwned>(json: &str) -> Result<T, Error> {
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let res: GraphQlResponse<T> = serde_path_to_error::deserialize(jd)?;
+
+    if !res.errors.is_empty() {
+        let msg = res.errors.into_iter().map(|e| e.message).collect::<Vec<_>>();
+        return Err(GithubFailure::GraphQl(msg.join(", ")).into());
+    }
+
+    res.data
+        .ok_or_else(|| GithubFailure::GraphQl("response had no data".to_owned()).into())
+}
+
+#[test]
+fn graphql_errors() {
+    let json = include_str!("../../test_json/graphql/errors.json");
+
+    let res = from_graphql_json::<serde_json::Value>(json);
+    assert!(matches!(
+        res,
+        Err(Error::Request(GithubFailure::GraphQl(msg)))
+            if msg == "Could not resolve to a node with the global id of 'foo'"
+    ))
+}
@@ -0,0 +1,3 @@
+pub mod pin_issue;
+pub mod transfer_issue;
+pub mod unpin_issue;
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use js_int::UInt;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::graphql::{from_graphql_response, graphql_request, GRAPHQL_PATH},
+    Error, GithubClient, GithubRequest, GithubResponse, MetaData,
+};
+
+const QUERY: &str = r#"mutation($issueId: ID!, $repositoryId: ID!) {
+  transferIssue(input: { issueId: $issueId, repositoryId: $repositoryId }) {
+    issue { number url }
+  }
+}"#;
+
+/// Transfer an issue to another repository owned by the same user or organization.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request<'a> {
+    /// The `node_id` of the issue to transfer.
+    pub issue_id: &'a str,
+
+    /// The `node_id` of the repository the issue is transferred to.
+    pub repository_id: &'a str,
+}
+
+impl<'a> GithubRequest for Request<'a> {
+    /// Metadata for the transfer_issue mutation.
+    const METADATA: MetaData = MetaData {
+        description: "Transfer an issue to a different repository",
+        method: Method::POST,
+        path: GRAPHQL_PATH,
+        name: "transfer_issue",
+        authentication: true,
+    };
+    type Response = Response;
+
+    fn to_request(self, github: &GithubClient) -> Result<reqwest::Request, Error> {
+        graphql_request(github, QUERY, self)
+    }
+}
+
+/// The issue in its new repository.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Response {
+    /// The number of the issue in the new repository.
+    pub number: UInt,
+
+    /// The public web page url of the issue in the new repository.
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Data {
+    transfer_issue: Payload,
+}
+
+#[derive(Deserialize)]
+struct Payload {
+    issue: Response,
+}
+
+#[async_trait]
+impl GithubResponse for Response {
+    async fn from_response(resp: reqwest::Response) -> Result<Self, Error> {
+        from_graphql_response::<Data>(resp).await.map(|data| data.transfer_issue.issue)
+    }
+}
+
+#[test]
+fn transfer_issue() {
+    use crate::api::graphql::from_graphql_json;
+
+    let json = include_str!("../../../../test_json/graphql/transfer_issue.json");
+
+    let data = from_graphql_json::<Data>(json).unwrap();
+    assert_eq!(data.transfer_issue.issue.number, js_int::uint!(42));
+}
@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use js_int::UInt;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::graphql::{from_graphql_response, graphql_request, GRAPHQL_PATH},
+    Error, GithubClient, GithubRequest, GithubResponse, MetaData,
+};
+
+const QUERY: &str = r#"mutation($issueId: ID!) {
+  unpinIssue(input: { issueId: $issueId }) {
+    issue { number isPinned }
+  }
+}"#;
+
+/// Unpin a pinned issue.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request<'a> {
+    /// The `node_id` of the issue to unpin.
+    pub issue_id: &'a str,
+}
+
+impl<'a> GithubRequest for Request<'a> {
+    /// Metadata for the unpin_issue mutation.
+    const METADATA: MetaData = MetaData {
+        description: "Unpin a pinned issue",
+        method: Method::POST,
+        path: GRAPHQL_PATH,
+        name: "unpin_issue",
+        authentication: true,
+    };
+    type Response = Response;
+
+    fn to_request(self, github: &GithubClient) -> Result<reqwest::Request, Error> {
+        graphql_request(github, QUERY, self)
+    }
+}
+
+/// The issue that was unpinned.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    /// The number of the issue.
+    pub number: UInt,
+
+    /// Is the issue now pinned.
+    pub is_pinned: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Data {
+    unpin_issue: Payload,
+}
+
+#[derive(Deserialize)]
+struct Payload {
+    issue: Response,
+}
+
+#[async_trait]
+impl GithubResponse for Response {
+    async fn from_response(resp: reqwest::Response) -> Result<Self, Error> {
+        from_graphql_response::<Data>(resp).await.map(|data| data.unpin_issue.issue)
+    }
+}
+
+#[test]
+fn unpin_issue() {
+    use crate::api::graphql::from_graphql_json;
+
+    let json = include_str!("../../../../test_json/graphql/unpin_issue.json");
+
+    let data = from_graphql_json::<Data>(json).unwrap();
+    assert_eq!(data.unpin_issue.issue.number, js_int::uint!(42));
+}
@@ -5,10 +5,14 @@ use js_int::{uint, UInt};
 use serde::Serialize;
 
 pub mod actions;
+pub mod deployment;
 pub mod issue;
 pub mod octocat;
+pub mod org;
 pub mod pull_request;
 pub mod repository;
+pub mod team;
+pub mod user;
 
 /// Specifies the types of repositories you want returned.
 #[derive(Clone, Debug, StringEnum)]
@@ -36,6 +40,9 @@ pub enum Type {
     ///
     /// Only supported when a Github app calls this endpoint.
     Internal,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for Type {
@@ -51,6 +58,9 @@ pub enum Direction {
 
     /// Descending order, largest to smallest.
     Desc,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// How the returned repositories are sorted.
@@ -68,6 +78,9 @@ pub enum Sort {
 
     /// Return only repositories that are forks.
     FullName,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// Filter issues by state.
@@ -82,6 +95,9 @@ pub enum StateQuery {
 
     /// Return all issues.
     All,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for StateQuery {
@@ -226,6 +242,18 @@ impl fmt::Display for ApplicationLydian {
     }
 }
 
+/// Enables the preview timeline endpoint list_timeline_events.
+///
+/// See https://docs.github.com/en/rest/reference/issues#list-timeline-events-for-an-issue-preview-notices
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct ApplicationMockingbird;
+
+impl fmt::Display for ApplicationMockingbird {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "application/vnd.github.mockingbird-preview+json".fmt(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::{SystemTime, UNIX_EPOCH};
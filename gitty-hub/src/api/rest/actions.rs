@@ -3,3 +3,4 @@ pub mod download_job_log;
 pub mod download_run_log;
 pub mod list_runs;
 pub mod rerun_workflow;
+pub mod review_pending_deployments;
@@ -0,0 +1,71 @@
+use github_derive::{github_rest_api, StringEnum};
+use js_int::UInt;
+
+use crate::api::{rest::ApplicationV3Json, IncomingDeployment};
+
+github_rest_api! {
+    metadata: {
+        description: "Approve or reject deployments waiting on environment protection rules",
+        method: POST,
+        path: "/repos/:owner/:repo/actions/runs/:run_id/pending_deployments",
+        name: "review_pending_deployments",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The identifier for this run.
+        #[github(path)]
+        pub run_id: UInt,
+
+        /// The ids of the environments to approve or reject.
+        #[github(body)]
+        pub environment_ids: Vec<UInt>,
+
+        /// Approve or reject the deployments.
+        #[github(body)]
+        pub state: ReviewState,
+
+        /// A comment to go along with the review.
+        #[github(body)]
+        pub comment: &'a str,
+    }
+
+    #[github(with = deployments)]
+    response: {
+        /// The deployments that were approved or rejected.
+        pub deployments: Vec<IncomingDeployment>,
+    }
+}
+
+/// The review of a pending deployment.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+pub enum ReviewState {
+    /// Let the deployment continue.
+    Approved,
+
+    /// Stop the deployment.
+    Rejected,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+pub(crate) fn deployments<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { deployments: serde::Deserialize::deserialize(deser)? })
+}
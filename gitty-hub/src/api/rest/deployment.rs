@@ -0,0 +1,4 @@
+pub mod create_deployment;
+pub mod create_deployment_status;
+pub mod list_deployment_statuses;
+pub mod list_deployments;
@@ -0,0 +1,126 @@
+use github_derive::github_rest_api;
+
+use crate::api::{rest::ApplicationV3Json, IncomingDeployment};
+
+github_rest_api! {
+    metadata: {
+        description: "Create a deployment for a ref",
+        method: POST,
+        path: "/repos/:owner/:repo/deployments",
+        name: "create_deployment",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The ref to deploy, this can be a branch, tag or SHA.
+        #[github(body)]
+        pub r#ref: &'a str,
+
+        /// The task to run ie. `deploy:migrations`.
+        ///
+        /// Defaults to `deploy`.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub task: Option<&'a str>,
+
+        /// Merge the default branch into the ref before deploying.
+        ///
+        /// Defaults to `true`.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub auto_merge: Option<bool>,
+
+        /// The status contexts that must pass before deploying.
+        ///
+        /// `None` checks every context, an empty list skips the checks.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub required_contexts: Option<Vec<&'a str>>,
+
+        /// Extra information passed along to the deployment.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub payload: Option<serde_json::Value>,
+
+        /// The environment to deploy to ie. `staging`.
+        ///
+        /// Defaults to `production`.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub environment: Option<&'a str>,
+
+        /// A short description of the deployment.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<&'a str>,
+
+        /// Will this environment be removed once it is no longer in use.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub transient_environment: Option<bool>,
+
+        /// Is this deployment to an environment users interact with.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub production_environment: Option<bool>,
+    }
+
+    #[github(with = deployment_or_merge)]
+    response: {
+        /// The deployment that was created.
+        ///
+        /// This is `None` when github merged the default branch into the ref instead
+        /// of deploying, a new deployment has to be created for the merged ref.
+        pub deployment: Option<IncomingDeployment>,
+
+        /// The message github sends when it merged the default branch.
+        pub message: Option<String>,
+    }
+}
+
+pub(crate) fn deployment_or_merge<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    use serde::{de::Error, Deserialize};
+
+    let json = serde_json::Value::deserialize(deser)?;
+    if json.get("id").is_some() {
+        Ok(Response {
+            deployment: Some(
+                IncomingDeployment::deserialize(&json).map_err(D::Error::custom)?,
+            ),
+            message: None,
+        })
+    } else {
+        Ok(Response {
+            deployment: None,
+            message: json.get("message").and_then(|m| m.as_str()).map(ToOwned::to_owned),
+        })
+    }
+}
+
+#[test]
+fn create_deployment() {
+    let json = include_str!("../../../../test_json/rest/create_deployment.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let resp = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert!(matches!(
+        resp,
+        Response { deployment: Some(IncomingDeployment { ref_: Some(ref_), .. }), message: None }
+            if ref_ == "topic-branch"
+    ));
+}
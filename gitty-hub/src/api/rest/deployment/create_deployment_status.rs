@@ -0,0 +1,78 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::{rest::ApplicationV3Json, DeploymentState, IncomingDeploymentStatus};
+
+github_rest_api! {
+    metadata: {
+        description: "Create a status for a deployment",
+        method: POST,
+        path: "/repos/:owner/:repo/deployments/:deployment_id/statuses",
+        name: "create_deployment_status",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The identifier of the deployment.
+        #[github(path)]
+        pub deployment_id: UInt,
+
+        /// The new state of the deployment.
+        #[github(body)]
+        pub state: DeploymentState,
+
+        /// The url of the deployment's output.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub log_url: Option<&'a str>,
+
+        /// A short description of the status.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<&'a str>,
+
+        /// Change the environment of the deployment.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub environment: Option<&'a str>,
+
+        /// The url of the deployed environment.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub environment_url: Option<&'a str>,
+
+        /// Mark earlier successful deployments to the same environment `inactive`.
+        ///
+        /// Defaults to `true`.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub auto_inactive: Option<bool>,
+    }
+
+    response: {
+        /// The status that was created.
+        #[serde(flatten)]
+        pub status: IncomingDeploymentStatus,
+    }
+}
+
+#[test]
+fn create_deployment_status() {
+    let json = include_str!("../../../../test_json/rest/create_deployment_status.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let resp = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert!(matches!(resp.status.state, DeploymentState::Success));
+}
@@ -0,0 +1,58 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::{rest::ApplicationV3Json, IncomingDeploymentStatus};
+
+github_rest_api! {
+    metadata: {
+        description: "List the statuses of a deployment",
+        method: GET,
+        path: "/repos/:owner/:repo/deployments/:deployment_id/statuses",
+        name: "list_deployment_statuses",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The identifier of the deployment.
+        #[github(path)]
+        pub deployment_id: UInt,
+
+        /// Result per page.
+        ///
+        /// Defaults to 30 and max 100.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::per_page")]
+        pub per_page: Option<UInt>,
+
+        /// Which page of the results to return.
+        ///
+        /// Defaults to 1.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::page")]
+        pub page: Option<UInt>,
+    }
+
+    #[github(with = statuses)]
+    response: {
+        pub statuses: Vec<IncomingDeploymentStatus>,
+    }
+}
+
+pub(crate) fn statuses<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { statuses: serde::Deserialize::deserialize(deser)? })
+}
@@ -0,0 +1,83 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::{rest::ApplicationV3Json, IncomingDeployment};
+
+github_rest_api! {
+    metadata: {
+        description: "List the deployments of a repository",
+        method: GET,
+        path: "/repos/:owner/:repo/deployments",
+        name: "list_deployments",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// Only return deployments of this SHA.
+        #[github(query)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sha: Option<&'a str>,
+
+        /// Only return deployments of this branch, tag or SHA.
+        #[github(query)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub r#ref: Option<&'a str>,
+
+        /// Only return deployments running this task.
+        #[github(query)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub task: Option<&'a str>,
+
+        /// Only return deployments to this environment.
+        #[github(query)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub environment: Option<&'a str>,
+
+        /// Result per page.
+        ///
+        /// Defaults to 30 and max 100.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::per_page")]
+        pub per_page: Option<UInt>,
+
+        /// Which page of the results to return.
+        ///
+        /// Defaults to 1.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::page")]
+        pub page: Option<UInt>,
+    }
+
+    #[github(with = deployments)]
+    response: {
+        pub deployments: Vec<IncomingDeployment>,
+    }
+}
+
+pub(crate) fn deployments<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { deployments: serde::Deserialize::deserialize(deser)? })
+}
+
+#[test]
+fn list_deployments() {
+    let json = include_str!("../../../../test_json/rest/list_deployments.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let resp = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert_eq!(resp.deployments.len(), 1);
+}
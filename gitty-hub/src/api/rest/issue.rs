@@ -1,3 +1,11 @@
+use js_int::UInt;
+use serde::Deserialize;
+
+use crate::api::{
+    datetime_opt, AuthorAssociation, Dt, IncomingIssue, IncomingTeam, IncomingUser,
+    LockReason,
+};
+
 pub mod assign_issue;
 pub mod create_comment;
 pub mod create_issue;
@@ -5,6 +13,380 @@ pub mod delete_comment;
 pub mod get_comment;
 pub mod get_issue;
 pub mod list_issue_comments;
+pub mod list_issue_events;
 pub mod list_repo_issues;
+pub mod list_timeline_events;
+pub mod lock_issue;
+pub mod unlock_issue;
 pub mod update_comment;
 pub mod update_issue;
+
+/// A single entry in the history of an issue or pull request.
+///
+/// This is returned by both the issue events and the timeline endpoints. Not every
+/// kind of event has every field, a `committed` event for example has no `actor`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TimelineEvent {
+    /// Numeric identifier of the event.
+    pub id: Option<UInt>,
+
+    /// String identifier of the event.
+    pub node_id: Option<String>,
+
+    /// The github api url of the event.
+    pub url: Option<String>,
+
+    /// The user who caused the event.
+    pub actor: Option<IncomingUser>,
+
+    /// The SHA of the commit that referenced this issue, if any.
+    pub commit_id: Option<String>,
+
+    /// The github api url of the commit that referenced this issue, if any.
+    pub commit_url: Option<String>,
+
+    /// The time in UTC when the event happened.
+    #[serde(default, deserialize_with = "datetime_opt")]
+    pub created_at: Option<Dt>,
+
+    /// The kind of event and any information specific to it.
+    #[serde(flatten, deserialize_with = "event_kind")]
+    pub kind: TimelineEventKind,
+}
+
+/// The different kinds of events that can happen to an issue or pull request.
+///
+/// Github adds new kinds of events fairly often, any event this crate does not know
+/// about is kept as [`TimelineEventKind::Unknown`]. A known event that fails to parse
+/// is an error.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TimelineEventKind {
+    /// The issue was added to a project board.
+    AddedToProject,
+
+    /// The issue was assigned to a user.
+    Assigned {
+        /// The user who was assigned.
+        assignee: IncomingUser,
+
+        /// The user who did the assigning.
+        assigner: Option<IncomingUser>,
+    },
+
+    /// The issue was closed.
+    Closed,
+
+    /// A comment was added to the issue.
+    ///
+    /// Only returned by the timeline endpoint.
+    Commented {
+        /// The body of the comment.
+        body: Option<String>,
+
+        /// The user who wrote the comment.
+        user: IncomingUser,
+
+        /// The public web page url.
+        html_url: Option<String>,
+
+        /// The association of the author to the repository.
+        #[serde(default)]
+        author_association: AuthorAssociation,
+    },
+
+    /// A commit was added to the pull request's branch.
+    ///
+    /// Only returned by the timeline endpoint.
+    Committed {
+        /// The SHA of the commit.
+        sha: String,
+
+        /// The commit message.
+        message: String,
+
+        /// The git author of the commit.
+        author: TimelineCommitter,
+
+        /// The git committer of the commit.
+        committer: TimelineCommitter,
+
+        /// The public web page url.
+        html_url: Option<String>,
+    },
+
+    /// The issue or pull request was linked to another issue or pull request.
+    Connected,
+
+    /// The pull request was converted to a draft.
+    ConvertToDraft,
+
+    /// The issue was created by converting a note in a project board.
+    ConvertedNoteToIssue,
+
+    /// The issue was referenced from another issue or pull request.
+    ///
+    /// Only returned by the timeline endpoint.
+    #[serde(rename = "cross-referenced")]
+    CrossReferenced {
+        /// The issue or pull request the reference came from.
+        source: Box<CrossReferenceSource>,
+    },
+
+    /// The issue was removed from a milestone.
+    Demilestoned {
+        /// The milestone the issue was removed from.
+        milestone: EventMilestone,
+    },
+
+    /// The issue or pull request was unlinked from another issue or pull request.
+    Disconnected,
+
+    /// The pull request's branch was deleted.
+    HeadRefDeleted,
+
+    /// The pull request's branch was force pushed.
+    HeadRefForcePushed,
+
+    /// The pull request's branch was restored.
+    HeadRefRestored,
+
+    /// A label was added to the issue.
+    Labeled {
+        /// The label that was added.
+        label: EventLabel,
+    },
+
+    /// The issue was locked.
+    Locked {
+        /// The reason the issue was locked.
+        lock_reason: Option<LockReason>,
+    },
+
+    /// The user was mentioned in the body of the issue.
+    Mentioned,
+
+    /// The issue was marked as a duplicate of another issue.
+    MarkedAsDuplicate,
+
+    /// The pull request was merged.
+    Merged,
+
+    /// The issue was added to a milestone.
+    Milestoned {
+        /// The milestone the issue was added to.
+        milestone: EventMilestone,
+    },
+
+    /// The issue was moved between columns of a project board.
+    MovedColumnsInProject,
+
+    /// The issue was pinned.
+    Pinned,
+
+    /// A pull request was marked as ready for review.
+    ReadyForReview,
+
+    /// The issue was referenced from a commit message.
+    Referenced,
+
+    /// The issue was removed from a project board.
+    RemovedFromProject,
+
+    /// The title of the issue was changed.
+    Renamed {
+        /// The old and new title.
+        rename: EventRename,
+    },
+
+    /// The issue was reopened.
+    Reopened,
+
+    /// A review of the pull request was dismissed.
+    ReviewDismissed {
+        /// Information about the dismissed review.
+        dismissed_review: DismissedReview,
+    },
+
+    /// A review was requested from a user or team.
+    ReviewRequested {
+        /// The user who requested the review.
+        review_requester: Option<IncomingUser>,
+
+        /// The user whose review was requested.
+        requested_reviewer: Option<IncomingUser>,
+
+        /// The team whose review was requested.
+        requested_team: Option<IncomingTeam>,
+    },
+
+    /// A review request was removed from a user or team.
+    ReviewRequestRemoved {
+        /// The user who removed the review request.
+        review_requester: Option<IncomingUser>,
+
+        /// The user whose review is no longer requested.
+        requested_reviewer: Option<IncomingUser>,
+
+        /// The team whose review is no longer requested.
+        requested_team: Option<IncomingTeam>,
+    },
+
+    /// The pull request was reviewed.
+    ///
+    /// Only returned by the timeline endpoint.
+    Reviewed {
+        /// The state of the review, `approved`, `commented` or `changes_requested`.
+        state: String,
+
+        /// The body of the review.
+        body: Option<String>,
+
+        /// The user who reviewed the pull request.
+        user: IncomingUser,
+
+        /// The public web page url.
+        html_url: Option<String>,
+
+        /// The time in UTC when the review was submitted.
+        #[serde(default, deserialize_with = "datetime_opt")]
+        submitted_at: Option<Dt>,
+    },
+
+    /// Someone subscribed to receive notifications for the issue.
+    Subscribed,
+
+    /// The issue was transferred to another repository.
+    Transferred,
+
+    /// A user was unassigned from the issue.
+    Unassigned {
+        /// The user who was unassigned.
+        assignee: IncomingUser,
+
+        /// The user who did the unassigning.
+        assigner: Option<IncomingUser>,
+    },
+
+    /// A label was removed from the issue.
+    Unlabeled {
+        /// The label that was removed.
+        label: EventLabel,
+    },
+
+    /// The issue was unlocked.
+    Unlocked,
+
+    /// An issue that was marked as a duplicate is no longer a duplicate.
+    UnmarkedAsDuplicate,
+
+    /// The issue was unpinned.
+    Unpinned,
+
+    /// Someone unsubscribed from receiving notifications for the issue.
+    Unsubscribed,
+
+    /// An event this crate does not know about.
+    #[serde(skip)]
+    Unknown {
+        /// The name of the event ie. `auto_merge_enabled`.
+        event: String,
+
+        /// The full JSON object of the event.
+        payload: serde_json::Value,
+    },
+}
+
+/// The label of a `labeled` or `unlabeled` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventLabel {
+    /// The name of the label.
+    pub name: String,
+
+    /// Background color of the label box.
+    pub color: String,
+}
+
+/// The milestone of a `milestoned` or `demilestoned` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventMilestone {
+    /// The title of the milestone.
+    pub title: String,
+}
+
+/// The title change of a `renamed` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventRename {
+    /// The previous title.
+    pub from: String,
+
+    /// The new title.
+    pub to: String,
+}
+
+/// The review that was dismissed in a `review_dismissed` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DismissedReview {
+    /// The state of the review before it was dismissed.
+    pub state: String,
+
+    /// Numeric identifier of the review.
+    pub review_id: UInt,
+
+    /// The message given when dismissing the review.
+    pub dismissal_message: Option<String>,
+
+    /// The SHA of the commit that dismissed the review, if any.
+    pub dismissal_commit_id: Option<String>,
+}
+
+/// The git author or committer of a `committed` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TimelineCommitter {
+    /// The git author's name.
+    pub name: String,
+
+    /// The git author's email.
+    pub email: String,
+
+    /// The time in UTC of the commit.
+    #[serde(default, deserialize_with = "datetime_opt")]
+    pub date: Option<Dt>,
+}
+
+/// Where a `cross-referenced` event came from.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CrossReferenceSource {
+    /// The kind of source, this is always `issue` (pull requests are issues).
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// The issue or pull request that referenced this issue.
+    pub issue: Option<IncomingIssue>,
+}
+
+fn event_kind<'de, D>(deser: D) -> Result<TimelineEventKind, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let payload = serde_json::Value::deserialize(deser)?;
+    let event = payload
+        .get("event")
+        .and_then(|ev| ev.as_str())
+        .ok_or_else(|| D::Error::missing_field("event"))?
+        .to_owned();
+
+    // serde reports a tag that is not a variant as an unknown variant, any other error
+    // is an event this crate knows about that github changed
+    let unknown = format!("unknown variant `{}`", event);
+    match TimelineEventKind::deserialize(&payload) {
+        Ok(kind) => Ok(kind),
+        Err(err) if err.to_string().starts_with(&unknown) => {
+            Ok(TimelineEventKind::Unknown { event, payload })
+        }
+        Err(err) => Err(D::Error::custom(format_args!("`{}` event: {}", event, err))),
+    }
+}
@@ -0,0 +1,92 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::rest::{issue::TimelineEvent, ApplicationV3Json};
+
+github_rest_api! {
+    metadata: {
+        description: "List all the events of an issue",
+        method: GET,
+        path: "/repos/:owner/:repo/issues/:issue_number/events",
+        name: "list_issue_events",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of the repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The number of the issue to fetch events for.
+        #[github(path)]
+        pub issue_number: UInt,
+
+        /// Result per page.
+        ///
+        /// Defaults to 30 and max 100.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::per_page")]
+        pub per_page: Option<UInt>,
+
+        /// Which page of the results to return.
+        ///
+        /// Defaults to 1.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::page")]
+        pub page: Option<UInt>,
+    }
+
+    #[github(with = events)]
+    response: {
+        /// The events of the issue, oldest first.
+        pub events: Vec<TimelineEvent>,
+    }
+}
+
+pub fn events<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { events: serde::Deserialize::deserialize(deser)? })
+}
+
+#[test]
+fn list_issue_events() {
+    use crate::api::rest::issue::TimelineEventKind;
+
+    let json = include_str!("../../../../test_json/rest/list_issue_events.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let res = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+
+    assert!(matches!(
+        res.events.as_slice(),
+        [
+            TimelineEvent { kind: TimelineEventKind::Labeled { label }, .. },
+            TimelineEvent { kind: TimelineEventKind::Assigned { .. }, .. },
+            TimelineEvent { kind: TimelineEventKind::Renamed { rename }, .. },
+            TimelineEvent { kind: TimelineEventKind::Referenced, commit_id: Some(_), .. },
+            TimelineEvent { kind: TimelineEventKind::Closed, .. },
+            TimelineEvent { kind: TimelineEventKind::Unknown { event, .. }, .. },
+        ] if label.name == "bug"
+            && rename.to == "Fix the thing"
+            && event == "auto_squash_enabled"
+    ))
+}
+
+#[test]
+fn list_issue_events_known_event_error() {
+    let json = r#"[{ "id": 1, "event": "labeled", "label": 5 }]"#;
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let err = serde_path_to_error::deserialize::<_, Response>(jd).unwrap_err();
+    assert!(err.to_string().contains("`labeled` event"), "{}", err);
+}
@@ -0,0 +1,83 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::rest::{issue::TimelineEvent, ApplicationMockingbird};
+
+github_rest_api! {
+    metadata: {
+        description: "List the timeline of an issue or pull request",
+        method: GET,
+        path: "/repos/:owner/:repo/issues/:issue_number/timeline",
+        name: "list_timeline_events",
+        authentication: true,
+    }
+
+    request: {
+        /// Required accept header to enable this feature.
+        #[github(header = ACCEPT)]
+        pub accept: ApplicationMockingbird,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of the repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The number of the issue or pull request to fetch the timeline for.
+        #[github(path)]
+        pub issue_number: UInt,
+
+        /// Result per page.
+        ///
+        /// Defaults to 30 and max 100.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::per_page")]
+        pub per_page: Option<UInt>,
+
+        /// Which page of the results to return.
+        ///
+        /// Defaults to 1.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::page")]
+        pub page: Option<UInt>,
+    }
+
+    #[github(with = events)]
+    response: {
+        /// The timeline of the issue, oldest first.
+        pub events: Vec<TimelineEvent>,
+    }
+}
+
+pub fn events<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { events: serde::Deserialize::deserialize(deser)? })
+}
+
+#[test]
+fn list_timeline_events() {
+    use crate::api::rest::issue::TimelineEventKind;
+
+    let json = include_str!("../../../../test_json/rest/list_timeline_events.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let res = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+
+    assert!(matches!(
+        res.events.as_slice(),
+        [
+            TimelineEvent { kind: TimelineEventKind::Commented { body: Some(body), .. }, .. },
+            TimelineEvent { kind: TimelineEventKind::Committed { sha, .. }, actor: None, .. },
+            TimelineEvent { kind: TimelineEventKind::CrossReferenced { source }, .. },
+            TimelineEvent { kind: TimelineEventKind::Reviewed { state, .. }, .. },
+            TimelineEvent { kind: TimelineEventKind::Merged, .. },
+        ] if body == "Me too"
+            && sha == "7638417db6d59f3c431d3e1f261cc637155684cd"
+            && source.issue.is_some()
+            && state == "approved"
+    ))
+}
@@ -0,0 +1,40 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::{rest::ApplicationV3Json, LockReason};
+
+github_rest_api! {
+    metadata: {
+        description: "Lock an issue or pull request's conversation",
+        method: PUT,
+        path: "/repos/:owner/:repo/issues/:issue_number/lock",
+        name: "lock_issue",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The number of the issue to lock.
+        #[github(path)]
+        pub issue_number: UInt,
+
+        /// The reason for locking the conversation.
+        #[github(body)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub lock_reason: Option<LockReason>,
+    }
+
+    /// An empty response with a status of 204.
+    response: { }
+}
@@ -0,0 +1,35 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::rest::ApplicationV3Json;
+
+github_rest_api! {
+    metadata: {
+        description: "Unlock an issue or pull request's conversation",
+        method: DELETE,
+        path: "/repos/:owner/:repo/issues/:issue_number/lock",
+        name: "unlock_issue",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The number of the issue to unlock.
+        #[github(path)]
+        pub issue_number: UInt,
+    }
+
+    /// An empty response with a status of 204.
+    response: { }
+}
@@ -0,0 +1,39 @@
+use github_derive::StringEnum;
+
+pub mod list_org_members;
+
+/// Filter the members of an organization.
+#[derive(Clone, Debug, Default, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+pub enum MemberFilter {
+    /// Return all members.
+    #[default]
+    All,
+
+    /// Return only members without two-factor authentication enabled.
+    ///
+    /// Only available to organization owners.
+    #[github_enum(rename = "2fa_disabled")]
+    TwoFaDisabled,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Filter the members of an organization by their role.
+#[derive(Clone, Debug, Default, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+pub enum OrgRole {
+    /// Return all members.
+    #[default]
+    All,
+
+    /// Return only the owners of the organization.
+    Admin,
+
+    /// Return only members who are not owners.
+    Member,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -0,0 +1,79 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::{
+    rest::{
+        org::{MemberFilter, OrgRole},
+        ApplicationV3Json,
+    },
+    IncomingUser,
+};
+
+github_rest_api! {
+    metadata: {
+        description: "List the members of an organization",
+        method: GET,
+        path: "/orgs/:org/members",
+        name: "list_org_members",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The name of the organization.
+        #[github(path)]
+        pub org: &'a str,
+
+        /// Filter the returned members.
+        ///
+        /// Defaults to `MemberFilter::All`.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::opt_default")]
+        pub filter: Option<MemberFilter>,
+
+        /// Filter the returned members by their role.
+        ///
+        /// Defaults to `OrgRole::All`.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::opt_default")]
+        pub role: Option<OrgRole>,
+
+        /// Result per page.
+        ///
+        /// Defaults to 30 and max 100.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::per_page")]
+        pub per_page: Option<UInt>,
+
+        /// Which page of the results to return.
+        ///
+        /// Defaults to 1.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::page")]
+        pub page: Option<UInt>,
+    }
+
+    #[github(with = members)]
+    response: {
+        pub members: Vec<IncomingUser>,
+    }
+}
+
+pub(crate) fn members<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { members: serde::Deserialize::deserialize(deser)? })
+}
+
+#[test]
+fn list_org_members() {
+    let json = include_str!("../../../../test_json/rest/list_org_members.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let members = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert_eq!(members.members.len(), 2);
+}
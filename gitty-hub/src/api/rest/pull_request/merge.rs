@@ -60,6 +60,8 @@ pub enum MergeMethod {
     Merge,
     Squash,
     Rebase,
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 #[derive(Clone, Debug, Deserialize, Incoming)]
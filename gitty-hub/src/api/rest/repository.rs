@@ -1,2 +1,44 @@
+use github_derive::StringEnum;
+use serde::Deserialize;
+
+use crate::api::{IncomingUser, Permissions};
+
+pub mod check_collaborator;
+pub mod get_collaborator_permission;
 pub mod get_repository;
+pub mod list_collaborators;
 pub mod list_org_repositories;
+
+/// Filter collaborators by how they are affiliated with the repository.
+#[derive(Clone, Debug, Default, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+pub enum Affiliation {
+    /// Outside collaborators of an organization owned repository.
+    Outside,
+
+    /// Collaborators with permissions to the repository, regardless of organization
+    /// membership.
+    Direct,
+
+    /// All collaborators the requester can see.
+    #[default]
+    All,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// A user who can access a repository.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Collaborator {
+    /// The collaborating user.
+    #[serde(flatten)]
+    pub user: IncomingUser,
+
+    /// What the collaborator is allowed to do in the repository.
+    #[serde(default)]
+    pub permissions: Permissions,
+
+    /// The name of the role the collaborator has ie. `admin` or a custom role.
+    pub role_name: Option<String>,
+}
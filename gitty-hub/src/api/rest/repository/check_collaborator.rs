@@ -0,0 +1,34 @@
+use github_derive::github_rest_api;
+
+use crate::api::rest::ApplicationV3Json;
+
+github_rest_api! {
+    metadata: {
+        description: "Check if a user is a collaborator of a repository",
+        method: GET,
+        path: "/repos/:owner/:repo/collaborators/:username",
+        name: "check_collaborator",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The login of the user.
+        #[github(path)]
+        pub username: &'a str,
+    }
+
+    /// A response of 204 means the user is a collaborator, 404 means they are not.
+    response: { }
+}
@@ -0,0 +1,72 @@
+use github_derive::github_rest_api;
+
+use crate::api::{rest::ApplicationV3Json, IncomingUser, RepoPermission};
+
+github_rest_api! {
+    metadata: {
+        description: "Get the permission level a user has in a repository",
+        method: GET,
+        path: "/repos/:owner/:repo/collaborators/:username/permission",
+        name: "get_collaborator_permission",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// The login of the user.
+        #[github(path)]
+        pub username: &'a str,
+    }
+
+    response: {
+        /// The permission level of the user.
+        ///
+        /// The `permission` field only ever reports `admin`, `write`, `read` or `none`,
+        /// `triage` and `maintain` are only found in `role_name`.
+        pub permission: RepoPermission,
+
+        /// The name of the role the user has ie. `maintain` or a custom role.
+        pub role_name: Option<String>,
+
+        /// The user whose permission was requested.
+        pub user: Option<IncomingUser>,
+    }
+}
+
+impl Response {
+    /// The most specific permission level of the user.
+    ///
+    /// This prefers `role_name` when it names one of the built in roles, so `triage`
+    /// and `maintain` are reported instead of `read` and `write`.
+    pub fn level(&self) -> RepoPermission {
+        match self.role_name.as_deref() {
+            Some("triage") => RepoPermission::Triage,
+            Some("maintain") => RepoPermission::Maintain,
+            _ => self.permission.clone(),
+        }
+    }
+}
+
+#[test]
+fn get_collaborator_permission() {
+    let json =
+        include_str!("../../../../test_json/rest/get_collaborator_permission.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let resp = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert!(matches!(resp.permission, RepoPermission::Write));
+    assert!(matches!(resp.level(), RepoPermission::Maintain));
+    assert!(resp.level().is_at_least(&RepoPermission::Write));
+    assert!(!resp.level().is_at_least(&RepoPermission::Admin));
+}
@@ -0,0 +1,74 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::rest::{
+    repository::{Affiliation, Collaborator},
+    ApplicationV3Json,
+};
+
+github_rest_api! {
+    metadata: {
+        description: "List the collaborators of a repository",
+        method: GET,
+        path: "/repos/:owner/:repo/collaborators",
+        name: "list_collaborators",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The owner of this repository.
+        #[github(path)]
+        pub owner: &'a str,
+
+        /// The name of this repository.
+        #[github(path)]
+        pub repo: &'a str,
+
+        /// Filter collaborators by their affiliation.
+        ///
+        /// Defaults to `Affiliation::All`.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::opt_default")]
+        pub affiliation: Option<Affiliation>,
+
+        /// Result per page.
+        ///
+        /// Defaults to 30 and max 100.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::per_page")]
+        pub per_page: Option<UInt>,
+
+        /// Which page of the results to return.
+        ///
+        /// Defaults to 1.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::page")]
+        pub page: Option<UInt>,
+    }
+
+    #[github(with = collaborators)]
+    response: {
+        pub collaborators: Vec<Collaborator>,
+    }
+}
+
+pub(crate) fn collaborators<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { collaborators: serde::Deserialize::deserialize(deser)? })
+}
+
+#[test]
+fn list_collaborators() {
+    let json = include_str!("../../../../test_json/rest/list_collaborators.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let resp = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert!(resp.collaborators[0].permissions.maintain);
+    assert!(!resp.collaborators[0].permissions.admin);
+}
@@ -0,0 +1,56 @@
+use github_derive::StringEnum;
+use serde::Deserialize;
+
+pub mod check_team_membership;
+pub mod list_team_members;
+pub mod list_teams;
+
+/// The role of a user in a team.
+#[derive(Clone, Debug, Default, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+pub enum TeamRole {
+    /// Any role, only used to filter.
+    #[default]
+    All,
+
+    /// A normal member of the team.
+    Member,
+
+    /// A maintainer of the team, they can add and remove members.
+    Maintainer,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The state of a team membership.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+pub enum MembershipState {
+    /// The user is a member of the team.
+    Active,
+
+    /// The user has been invited but has not yet accepted.
+    Pending,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// A user's membership in a team.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TeamMembership {
+    /// The github api url of the membership.
+    pub url: String,
+
+    /// The role the user has in the team.
+    pub role: TeamRole,
+
+    /// Is the membership active or still waiting on the user to accept.
+    pub state: MembershipState,
+}
+
+impl TeamMembership {
+    /// Returns `true` if the user has accepted the membership.
+    pub fn is_active(&self) -> bool { matches!(self.state, MembershipState::Active) }
+}
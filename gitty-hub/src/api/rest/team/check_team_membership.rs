@@ -0,0 +1,50 @@
+use github_derive::github_rest_api;
+
+use crate::api::rest::{team::TeamMembership, ApplicationV3Json};
+
+github_rest_api! {
+    metadata: {
+        description: "Get a user's membership in a team",
+        method: GET,
+        path: "/orgs/:org/teams/:team_slug/memberships/:username",
+        name: "check_team_membership",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The name of the organization.
+        #[github(path)]
+        pub org: &'a str,
+
+        /// The slug of the team ie. `justice-league`.
+        #[github(path)]
+        pub team_slug: &'a str,
+
+        /// The login of the user.
+        #[github(path)]
+        pub username: &'a str,
+    }
+
+    response: {
+        /// The user's membership, check [`TeamMembership::is_active`] as invited users
+        /// are also returned.
+        ///
+        /// If the user is not a member of the team the request fails with a
+        /// `404 Not Found` [`crate::GithubFailure::StatusError`].
+        #[serde(flatten)]
+        pub membership: TeamMembership,
+    }
+}
+
+#[test]
+fn check_team_membership() {
+    let json = include_str!("../../../../test_json/rest/check_team_membership.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let resp = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert!(resp.membership.is_active());
+}
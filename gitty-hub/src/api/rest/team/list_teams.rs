@@ -0,0 +1,59 @@
+use github_derive::github_rest_api;
+use js_int::UInt;
+
+use crate::api::{rest::ApplicationV3Json, IncomingTeam};
+
+github_rest_api! {
+    metadata: {
+        description: "List the teams of an organization that are visible to the user",
+        method: GET,
+        path: "/orgs/:org/teams",
+        name: "list_teams",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The name of the organization.
+        #[github(path)]
+        pub org: &'a str,
+
+        /// Result per page.
+        ///
+        /// Defaults to 30 and max 100.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::per_page")]
+        pub per_page: Option<UInt>,
+
+        /// Which page of the results to return.
+        ///
+        /// Defaults to 1.
+        #[github(query)]
+        #[serde(serialize_with = "crate::api::rest::page")]
+        pub page: Option<UInt>,
+    }
+
+    #[github(with = teams)]
+    response: {
+        pub teams: Vec<IncomingTeam>,
+    }
+}
+
+pub(crate) fn teams<'de, D>(deser: D) -> Result<Response, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Response { teams: serde::Deserialize::deserialize(deser)? })
+}
+
+#[test]
+fn list_teams() {
+    let json = include_str!("../../../../test_json/rest/list_teams.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let teams = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert_eq!(teams.teams[0].slug, "justice-league");
+}
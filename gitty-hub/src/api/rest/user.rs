@@ -0,0 +1,66 @@
+use js_int::UInt;
+use serde::Deserialize;
+
+use crate::api::{datetime_opt, Dt, IncomingUser};
+
+pub mod get_authenticated_user;
+pub mod get_user;
+
+/// The public profile of a user.
+///
+/// This is the information returned when a user is requested directly, webhooks only
+/// include the fields in [`IncomingUser`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct UserProfile {
+    /// The basic information about the user.
+    #[serde(flatten)]
+    pub user: IncomingUser,
+
+    /// The display name of the user.
+    pub name: Option<String>,
+
+    /// The company the user works for.
+    pub company: Option<String>,
+
+    /// Url to a blog associated with this user.
+    pub blog: Option<String>,
+
+    /// The location of this user.
+    pub location: Option<String>,
+
+    /// The public email address of this user.
+    pub email: Option<String>,
+
+    /// Is this user available for hire.
+    pub hireable: Option<bool>,
+
+    /// The biography of the user.
+    pub bio: Option<String>,
+
+    /// The twitter user associated with this user.
+    pub twitter_username: Option<String>,
+
+    /// Number of public repositories.
+    #[serde(default)]
+    pub public_repos: UInt,
+
+    /// Number of public gists.
+    #[serde(default)]
+    pub public_gists: UInt,
+
+    /// Number of followers.
+    #[serde(default)]
+    pub followers: UInt,
+
+    /// Number of users this user is following.
+    #[serde(default)]
+    pub following: UInt,
+
+    /// Time in UTC this user was created.
+    #[serde(default, deserialize_with = "datetime_opt")]
+    pub created_at: Option<Dt>,
+
+    /// Time in UTC this user was last updated.
+    #[serde(default, deserialize_with = "datetime_opt")]
+    pub updated_at: Option<Dt>,
+}
@@ -0,0 +1,25 @@
+use github_derive::github_rest_api;
+
+use crate::api::rest::{user::UserProfile, ApplicationV3Json};
+
+github_rest_api! {
+    metadata: {
+        description: "Get the profile of the user the token belongs to",
+        method: GET,
+        path: "/user",
+        name: "get_authenticated_user",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+    }
+
+    response: {
+        /// The authenticated user.
+        #[serde(flatten)]
+        pub user: UserProfile,
+    }
+}
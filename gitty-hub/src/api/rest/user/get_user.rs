@@ -0,0 +1,39 @@
+use github_derive::github_rest_api;
+
+use crate::api::rest::{user::UserProfile, ApplicationV3Json};
+
+github_rest_api! {
+    metadata: {
+        description: "Get the public profile of a user",
+        method: GET,
+        path: "/users/:username",
+        name: "get_user",
+        authentication: true,
+    }
+
+    request: {
+        /// Optional accept header to enable preview features.
+        #[github(header = ACCEPT)]
+        pub accept: Option<ApplicationV3Json>,
+
+        /// The login of the user.
+        #[github(path)]
+        pub username: &'a str,
+    }
+
+    response: {
+        /// The requested user.
+        #[serde(flatten)]
+        pub user: UserProfile,
+    }
+}
+
+#[test]
+fn get_user() {
+    let json = include_str!("../../../../test_json/rest/get_user.json");
+
+    let jd = &mut serde_json::Deserializer::from_str(json);
+    let user = serde_path_to_error::deserialize::<_, Response>(jd).unwrap();
+    assert_eq!(user.user.user.login, "octocat");
+    assert_eq!(user.user.name.as_deref(), Some("monalisa octocat"));
+}
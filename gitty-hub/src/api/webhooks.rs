@@ -1,126 +1,484 @@
 pub mod check_run;
 pub mod check_suite;
+pub mod code_scanning_alert;
 pub mod commit_comment;
 pub mod common;
+#[cfg(test)]
+mod conformance;
+pub mod content_reference;
 pub mod create;
 pub mod delete;
+pub mod delivery;
+pub mod dependabot_alert;
+pub mod deploy_key;
+pub mod deployment;
+pub mod deployment_review;
+pub mod deployment_status;
+pub mod discussion;
+pub mod discussion_comment;
+pub mod fork;
+pub mod gollum;
 pub mod installation;
 pub mod issue;
 pub mod issue_comment;
+pub mod label;
+pub mod member;
+pub mod membership;
 pub mod milestone;
+pub mod org_block;
+pub mod organization;
+pub mod page_build;
+mod payload;
+pub mod ping;
+pub mod project;
+pub mod project_card;
+pub mod project_column;
+pub mod public;
 pub mod pull;
 pub mod pull_review;
 pub mod pull_review_comment;
 pub mod push;
+pub mod registry_package;
 pub mod release;
+pub mod repository;
+pub mod repository_dispatch;
+pub mod repository_vulnerability_alert;
+pub mod secret_scanning_alert;
 pub mod star;
 pub mod status;
+pub mod team;
+pub mod team_add;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod verify;
 pub mod watch;
+pub mod workflow_dispatch;
+pub mod workflow_job;
+pub mod workflow_run;
 
 use check_run::CheckRunEvent;
 use check_suite::CheckSuiteEvent;
+use code_scanning_alert::CodeScanningAlertEvent;
 use commit_comment::CommitCommentEvent;
 use common::EventKind;
+use content_reference::ContentReferenceEvent;
 use create::CreateEvent;
 use delete::DeleteEvent;
+pub use delivery::WebhookDelivery;
+use dependabot_alert::DependabotAlertEvent;
+use deploy_key::DeployKeyEvent;
+use deployment::DeploymentEvent;
+use deployment_review::DeploymentReviewEvent;
+use deployment_status::DeploymentStatusEvent;
+use discussion::DiscussionEvent;
+use discussion_comment::DiscussionCommentEvent;
+use fork::ForkEvent;
+use github_derive::IntoOwned;
+use gollum::GollumEvent;
 use installation::InstallationEvent;
 use issue::IssueEvent;
 use issue_comment::IssueCommentEvent;
+use label::LabelEvent;
+use member::MemberEvent;
+use membership::MembershipEvent;
 use milestone::MilestoneEvent;
+use org_block::OrgBlockEvent;
+use organization::OrganizationEvent;
+use page_build::PageBuildEvent;
+pub use payload::WebhookPayload;
+use ping::PingEvent;
+use project::ProjectEvent;
+use project_card::ProjectCardEvent;
+use project_column::ProjectColumnEvent;
+use public::PublicEvent;
 use pull::PullRequestEvent;
 use pull_review::PullRequestReviewEvent;
 use pull_review_comment::PullRequestReviewCommentEvent;
 use push::PushEvent;
+use registry_package::RegistryPackageEvent;
 use release::ReleaseEvent;
+use repository::RepositoryEvent;
+use repository_dispatch::RepositoryDispatchEvent;
+use repository_vulnerability_alert::RepositoryVulnerabilityAlertEvent;
+use secret_scanning_alert::SecretScanningAlertEvent;
+use serde::Deserialize;
 use star::StarEvent;
 use status::StatusEvent;
+use team::TeamEvent;
+use team_add::TeamAddEvent;
+use thiserror::Error;
 use watch::WatchEvent;
+use workflow_dispatch::WorkflowDispatchEvent;
+use workflow_job::WorkflowJobEvent;
+use workflow_run::WorkflowRunEvent;
+
+use crate::utils::IntoOwned;
+
+/// Errors that can happen while parsing a webhook payload.
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    /// The payload did not match the event's type.
+    ///
+    /// The path to the field that failed is available with [`WebhookError::path`].
+    #[error("failed to parse webhook payload: {0}")]
+    Json(#[from] serde_path_to_error::Error<serde_json::Error>),
+
+    /// A header needed to parse the delivery was missing.
+    #[error("missing the `{0}` header")]
+    MissingHeader(&'static str),
+
+    /// The payload had keys that no field captured, ie. `issue.user.email`.
+    ///
+    /// This is only returned with the `strict` feature.
+    #[cfg(feature = "strict")]
+    #[error("keys no field captured: {}", .0.join(", "))]
+    UnknownFields(Vec<String>),
+
+    /// The parsed payload could not be serialized to find the keys no field captured.
+    ///
+    /// This is only returned with the `strict` feature.
+    #[cfg(feature = "strict")]
+    #[error("failed to serialize webhook payload: {0}")]
+    Serialize(serde_json::Error),
+}
+
+impl WebhookError {
+    /// The path to the JSON field that failed to deserialize, ie. `issue.user.login`.
+    pub fn path(&self) -> Option<&serde_path_to_error::Path> {
+        match self {
+            Self::Json(err) => Some(err.path()),
+            Self::MissingHeader(_) => None,
+            #[cfg(feature = "strict")]
+            Self::UnknownFields(_) | Self::Serialize(_) => None,
+        }
+    }
+}
+
+/// Deserializes a payload, the path to the failing field is only tracked after
+/// parsing failed since tracking it makes parsing about twice as slow.
+fn from_str<'de, T: Deserialize<'de>>(body: &'de str) -> Result<T, WebhookError> {
+    serde_json::from_str(body).or_else(|_| {
+        let jd = &mut serde_json::Deserializer::from_str(body);
+        serde_path_to_error::deserialize(jd).map_err(WebhookError::Json)
+    })
+}
+
+/// Collects the paths of keys in `raw` that are missing from `parsed`.
+///
+/// Array indices are written as `[]` so a key missing from every element of an array
+/// is reported once.
+#[cfg(any(test, feature = "strict"))]
+pub(crate) fn unknown_keys(
+    raw: &serde_json::Value,
+    parsed: &serde_json::Value,
+    path: &str,
+    unknown: &mut std::collections::BTreeSet<String>,
+) {
+    match (raw, parsed) {
+        (serde_json::Value::Object(raw), serde_json::Value::Object(parsed)) => {
+            for (key, raw) in raw {
+                let path = if path.is_empty() {
+                    key.to_owned()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match parsed.get(key) {
+                    Some(parsed) => unknown_keys(raw, parsed, &path, unknown),
+                    None => {
+                        unknown.insert(path);
+                    }
+                }
+            }
+        }
+        (serde_json::Value::Array(raw), serde_json::Value::Array(parsed)) => {
+            let path = format!("{}[]", path);
+            for (raw, parsed) in raw.iter().zip(parsed) {
+                unknown_keys(raw, parsed, &path, unknown);
+            }
+        }
+        _ => {}
+    }
+}
 
-/// Describes the GitHub webhook event that triggered this request.
+/// Generates `GitHubEvent` along with `as_kind` and `from_raw` from one table.
 ///
-/// `GithubEvent` does not implement `Deserialize` because there is no information in
-/// the payload to find which variant, that information is in the request header.
-#[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug)]
-pub enum GitHubEvent<'req> {
-    /// The check run payload of a github webhook.
-    CheckRun(CheckRunEvent<'req>),
+/// Every variant is written as `Variant(PayloadType) => EventKindVariant`, the
+/// `Unknown` variant is added for events without a row.
+macro_rules! github_events {
+    (
+        $( #[$enum_meta:meta] )*
+        pub enum GitHubEvent<$req:lifetime> {
+            $(
+                $( #[$meta:meta] )*
+                $variant:ident($payload:ty) => $kind:ident,
+            )*
+        }
+    ) => {
+        $( #[$enum_meta] )*
+        pub enum GitHubEvent<$req> {
+            $(
+                $( #[$meta] )*
+                $variant($payload),
+            )*
+
+            /// An event gitty-hub has no payload type for.
+            ///
+            /// This is also used for events github adds after this version of gitty-hub
+            /// in which case `kind` is [`EventKind`]'s hidden catch all.
+            Unknown {
+                /// The event name from the `X-GitHub-Event` header.
+                kind: EventKind,
+
+                /// The untyped payload.
+                payload: serde_json::Value,
+            },
+        }
+
+        impl ::serde::Serialize for GitHubEvent<'_> {
+            fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                match self {
+                    $( GitHubEvent::$variant(ev) => ev.serialize(ser), )*
+                    GitHubEvent::Unknown { payload, .. } => payload.serialize(ser),
+                }
+            }
+        }
+
+        impl<$req> GitHubEvent<$req> {
+            pub fn as_kind(&self) -> EventKind {
+                match self {
+                    $( GitHubEvent::$variant(_) => EventKind::$kind, )*
+                    GitHubEvent::Unknown { kind, .. } => kind.clone(),
+                }
+            }
+
+            /// Convert into an event that owns all of its data.
+            ///
+            /// The returned event no longer borrows the request body so it can be sent
+            /// over a channel or stored.
+            pub fn into_owned(self) -> GitHubEvent<'static> {
+                <Self as IntoOwned>::into_owned(self)
+            }
+
+            /// The payload as a [`WebhookPayload`], `None` if the event is not known.
+            fn payload(&self) -> Option<&dyn WebhookPayload> {
+                match self {
+                    $( GitHubEvent::$variant(ev) => Some(ev), )*
+                    GitHubEvent::Unknown { .. } => None,
+                }
+            }
+
+            /// Parse the `body` of a webhook request using the event name `kind` from
+            /// the `X-GitHub-Event` header.
+            ///
+            /// Events without a payload type are parsed as [`GitHubEvent::Unknown`].
+            pub fn from_raw(kind: &str, body: &$req str) -> Result<Self, WebhookError> {
+                let event = Self::from_raw_lenient(kind, body)?;
+                #[cfg(feature = "strict")]
+                event.deny_unknown_fields(body)?;
+                Ok(event)
+            }
+
+            /// [`GitHubEvent::from_raw`] without the `strict` feature's check for keys
+            /// no field captured.
+            fn from_raw_lenient(kind: &str, body: &$req str) -> Result<Self, WebhookError> {
+                // Only a function is picked per event so there is one event on the stack,
+                // in a debug build parsing inline needed megabytes of stack
+                let parse: fn(&$req str) -> Result<Self, WebhookError> = $(
+                    if kind == EventKind::$kind.as_ref() {
+                        |body| from_str(body).map(GitHubEvent::$variant)
+                    } else
+                )* {
+                    let payload = from_str(body)?;
+                    return Ok(GitHubEvent::Unknown { kind: EventKind::from(kind), payload });
+                };
+                parse(body)
+            }
+        }
+    };
+}
+
+github_events! {
+    /// Describes the GitHub webhook event that triggered this request.
+    ///
+    /// `GithubEvent` does not implement `Deserialize` because there is no information in
+    /// the payload to find which variant, that information is in the request header, use
+    /// [`GitHubEvent::from_raw`] instead.
+    ///
+    /// `GithubEvent` serializes as just the payload, to replay a stored event pass
+    /// [`GitHubEvent::as_kind`] and the JSON back to [`GitHubEvent::from_raw`].
+    #[allow(clippy::large_enum_variant)]
+    #[derive(Clone, Debug, IntoOwned)]
+    pub enum GitHubEvent<'req> {
+        /// The check run payload of a github webhook.
+        CheckRun(CheckRunEvent<'req>) => CheckRun,
+
+        /// The check suite payload of a github webhook.
+        CheckSuite(CheckSuiteEvent<'req>) => CheckSuite,
+
+        /// The code scanning alert payload of a github webhook.
+        CodeScanningAlert(CodeScanningAlertEvent<'req>) => CodeScanningAlert,
+
+        /// The check suite payload of a github webhook.
+        CommitComment(CommitCommentEvent<'req>) => CommitComment,
+
+        /// The content reference payload of a github webhook.
+        ContentReference(ContentReferenceEvent<'req>) => ContentReference,
+
+        /// The create payload of a github webhook.
+        Create(CreateEvent<'req>) => Create,
+
+        /// The create payload of a github webhook.
+        Delete(DeleteEvent<'req>) => Delete,
+
+        /// The dependabot alert payload of a github webhook.
+        DependabotAlert(DependabotAlertEvent<'req>) => DependabotAlert,
+
+        /// The deploy key payload of a github webhook.
+        DeployKey(DeployKeyEvent<'req>) => DeployKey,
+
+        /// The deployment payload of a github webhook.
+        Deployment(DeploymentEvent<'req>) => Deployment,
+
+        /// The deployment review payload of a github webhook.
+        DeploymentReview(DeploymentReviewEvent<'req>) => DeploymentReview,
+
+        /// The deployment status payload of a github webhook.
+        DeploymentStatus(DeploymentStatusEvent<'req>) => DeploymentStatus,
+
+        /// The discussion payload of a github webhook.
+        Discussion(DiscussionEvent<'req>) => Discussion,
+
+        /// The discussion comment payload of a github webhook.
+        DiscussionComment(DiscussionCommentEvent<'req>) => DiscussionComment,
+
+        /// The fork payload of a github webhook.
+        Fork(ForkEvent<'req>) => Fork,
+
+        /// The gollum payload of a github webhook.
+        Gollum(GollumEvent<'req>) => Gollum,
+
+        /// The installation payload of a github webhook.
+        Installation(InstallationEvent<'req>) => Installation,
+
+        /// The issue payload of a github webhook.
+        Issue(IssueEvent<'req>) => Issues,
+
+        /// The issue comment payload of a github webhook.
+        IssueComment(IssueCommentEvent<'req>) => IssueComment,
+
+        /// The label payload of a github webhook.
+        Label(LabelEvent<'req>) => Label,
+
+        /// The member payload of a github webhook.
+        Member(MemberEvent<'req>) => Member,
+
+        /// The membership payload of a github webhook.
+        Membership(MembershipEvent<'req>) => Membership,
+
+        /// The milestone payload of a github webhook.
+        Milestone(MilestoneEvent<'req>) => Milestone,
+
+        /// The organization payload of a github webhook.
+        Organization(OrganizationEvent<'req>) => Organization,
+
+        /// The org block payload of a github webhook.
+        OrgBlock(OrgBlockEvent<'req>) => OrgBlock,
+
+        /// The page build payload of a github webhook.
+        PageBuild(PageBuildEvent<'req>) => PageBuild,
+
+        /// The ping payload of a github webhook.
+        Ping(PingEvent<'req>) => Ping,
+
+        /// The project payload of a github webhook.
+        Project(ProjectEvent<'req>) => Project,
 
-    /// The check suite payload of a github webhook.
-    CheckSuite(CheckSuiteEvent<'req>),
+        /// The project card payload of a github webhook.
+        ProjectCard(ProjectCardEvent<'req>) => ProjectCard,
 
-    /// The check suite payload of a github webhook.
-    CommitComment(CommitCommentEvent<'req>),
+        /// The project column payload of a github webhook.
+        ProjectColumn(ProjectColumnEvent<'req>) => ProjectColumn,
 
-    /// The create payload of a github webhook.
-    Create(CreateEvent<'req>),
+        /// The public payload of a github webhook.
+        Public(PublicEvent<'req>) => Public,
 
-    /// The create payload of a github webhook.
-    Delete(DeleteEvent<'req>),
+        /// The pull request payload of a github webhook.
+        PullRequest(PullRequestEvent<'req>) => PullRequest,
 
-    /// The installation payload of a github webhook.
-    Installation(InstallationEvent<'req>),
+        /// The pull request review payload of a github webhook.
+        PullRequestReview(PullRequestReviewEvent<'req>) => PullRequestReview,
 
-    /// The issue payload of a github webhook.
-    Issue(IssueEvent<'req>),
+        /// The pull request review comment payload of a github webhook.
+        PullRequestReviewComment(PullRequestReviewCommentEvent<'req>)
+            => PullRequestReviewComment,
 
-    /// The issue comment payload of a github webhook.
-    IssueComment(IssueCommentEvent<'req>),
+        /// The push payload of a github webhook.
+        Push(PushEvent<'req>) => Push,
 
-    /// The milestone payload of a github webhook.
-    Milestone(MilestoneEvent<'req>),
+        /// The registry package payload of a github webhook.
+        RegistryPackage(RegistryPackageEvent<'req>) => RegistryPackage,
 
-    /// The milestone payload of a github webhook.
-    Ping(serde_json::Value),
+        /// The release comment payload of a github webhook.
+        Release(ReleaseEvent<'req>) => Release,
 
-    /// The pull request payload of a github webhook.
-    PullRequest(PullRequestEvent<'req>),
+        /// The repository payload of a github webhook.
+        Repository(RepositoryEvent<'req>) => Repository,
 
-    /// The pull request review payload of a github webhook.
-    PullRequestReview(PullRequestReviewEvent<'req>),
+        /// The repository dispatch payload of a github webhook.
+        RepositoryDispatch(RepositoryDispatchEvent<'req>) => RepositoryDispatch,
 
-    /// The pull request review comment payload of a github webhook.
-    PullRequestReviewComment(PullRequestReviewCommentEvent<'req>),
+        /// The repository vulnerability alert payload of a github webhook.
+        RepositoryVulnerabilityAlert(RepositoryVulnerabilityAlertEvent<'req>)
+            => RepositoryVulnerabilityAlert,
 
-    /// The push payload of a github webhook.
-    Push(PushEvent<'req>),
+        /// The secret scanning alert payload of a github webhook.
+        SecretScanningAlert(SecretScanningAlertEvent<'req>) => SecretScanningAlert,
 
-    /// The release comment payload of a github webhook.
-    Release(ReleaseEvent<'req>),
+        /// The stared payload of a github webhook.
+        Star(StarEvent<'req>) => Star,
 
-    /// The stared payload of a github webhook.
-    Star(StarEvent<'req>),
+        /// The status payload of a github webhook.
+        Status(StatusEvent<'req>) => Status,
 
-    /// The status payload of a github webhook.
-    Status(StatusEvent<'req>),
+        /// The team payload of a github webhook.
+        Team(TeamEvent<'req>) => Team,
 
-    /// The watch payload of a github webhook.
-    Watch(WatchEvent<'req>),
+        /// The team add payload of a github webhook.
+        TeamAdd(TeamAddEvent<'req>) => TeamAdd,
+
+        /// The watch payload of a github webhook.
+        Watch(WatchEvent<'req>) => Watch,
+
+        /// The workflow dispatch payload of a github webhook.
+        WorkflowDispatch(WorkflowDispatchEvent<'req>) => WorkflowDispatch,
+
+        /// The workflow job payload of a github webhook.
+        WorkflowJob(WorkflowJobEvent<'req>) => WorkflowJob,
+
+        /// The workflow run payload of a github webhook.
+        WorkflowRun(WorkflowRunEvent<'req>) => WorkflowRun,
+    }
 }
 
-impl<'a> GitHubEvent<'a> {
-    pub fn as_kind(&self) -> EventKind {
-        match self {
-            GitHubEvent::CheckRun(_) => EventKind::CheckRun,
-            GitHubEvent::CheckSuite(_) => EventKind::CheckSuite,
-            GitHubEvent::CommitComment(_) => EventKind::CommitComment,
-            GitHubEvent::Create(_) => EventKind::Create,
-            GitHubEvent::Delete(_) => EventKind::Delete,
-            GitHubEvent::Installation(_) => EventKind::Installation,
-            GitHubEvent::Issue(_) => EventKind::Issues,
-            GitHubEvent::IssueComment(_) => EventKind::IssueComment,
-            GitHubEvent::Milestone(_) => EventKind::Milestone,
-            GitHubEvent::Ping(_) => EventKind::Ping,
-            GitHubEvent::PullRequest(_) => EventKind::PullRequest,
-            GitHubEvent::PullRequestReview(_) => EventKind::PullRequestReview,
-            GitHubEvent::PullRequestReviewComment(_) => {
-                EventKind::PullRequestReviewComment
-            }
-            GitHubEvent::Push(_) => EventKind::Push,
-            GitHubEvent::Release(_) => EventKind::Release,
-            GitHubEvent::Star(_) => EventKind::Star,
-            GitHubEvent::Status(_) => EventKind::Status,
-            GitHubEvent::Watch(_) => EventKind::Watch,
+#[cfg(feature = "strict")]
+impl GitHubEvent<'_> {
+    /// Fails if `body` has keys that no field of the parsed event captured.
+    ///
+    /// The keys are found by serializing the event and comparing it to `body`, so this is
+    /// only as accurate as the `Serialize` impls of the payload types.
+    fn deny_unknown_fields(&self, body: &str) -> Result<(), WebhookError> {
+        let raw = from_str(body)?;
+        let parsed = serde_json::to_value(self).map_err(WebhookError::Serialize)?;
+
+        let mut unknown = std::collections::BTreeSet::new();
+        unknown_keys(&raw, &parsed, "", &mut unknown);
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(WebhookError::UnknownFields(unknown.into_iter().collect()))
         }
     }
 }
@@ -132,20 +490,61 @@ mod test {
     use js_int::uint;
 
     use crate::api::webhooks::{
-        check_run::{CheckRun, CheckRunEvent, Deployment, Output},
+        check_run::{CheckRun, CheckRunEvent, Output},
         check_suite::{CheckAction, CheckSuite, CheckSuiteEvent},
+        code_scanning_alert::{
+            CodeScanningAlert, CodeScanningAlertAction, CodeScanningAlertEvent,
+            CodeScanningAlertInstance, CodeScanningAlertState, CodeScanningLocation,
+            CodeScanningRule, RuleSeverity,
+        },
         commit_comment::{CommitComment, CommitCommentAction, CommitCommentEvent},
         common::{
-            App, AuthorAssociation, Changes, Comment, Commit, CommitInner, Committer,
-            Installation, Issue, IssueState, Milestone, PullRequest, Repository, Type,
-            User,
+            AlertSeverity, App, AuthorAssociation, Changes, CheckStatus, Comment, Commit,
+            CommitInner, Committer, ConclusionStatus, Deployment, DeploymentState,
+            DeploymentStatus, EventKind, Installation, Issue, IssueState, Label,
+            Milestone, PullRequest, Repository, SimpleOrg, Team, Type, User, Workflow,
+            WorkflowRun,
         },
+        content_reference::{ContentReferenceAction, ContentReferenceEvent},
         create::{CreateEvent, RefType},
         delete::DeleteEvent,
+        dependabot_alert::{
+            DependabotAlert, DependabotAlertAction, DependabotAlertEvent,
+            DependabotAlertState, SecurityAdvisory, SecurityVulnerability,
+        },
+        deploy_key::{DeployKey, DeployKeyAction, DeployKeyEvent},
+        deployment::{DeploymentAction, DeploymentEvent},
+        deployment_review::{
+            DeploymentReviewAction, DeploymentReviewEvent, Reviewer, ReviewerType,
+        },
+        deployment_status::{DeploymentStatusAction, DeploymentStatusEvent},
+        discussion::{
+            Discussion, DiscussionAction, DiscussionCategory, DiscussionComment,
+            DiscussionEvent, DiscussionState,
+        },
+        discussion_comment::{DiscussionCommentAction, DiscussionCommentEvent},
+        fork::ForkEvent,
+        gollum::{GollumEvent, WikiPageAction},
         installation::{InstallationAction, InstallationEvent},
         issue::{IssueAction, IssueEvent},
         issue_comment::{IssueCommentAction, IssueCommentEvent},
-        milestone::{MilestoneAction, MilestoneEvent},
+        label::{LabelAction, LabelChanges, LabelEvent},
+        member::{MemberAction, MemberEvent},
+        membership::{MembershipAction, MembershipEvent, MembershipScope},
+        milestone::{MilestoneAction, MilestoneChanges, MilestoneEvent},
+        org_block::{OrgBlockAction, OrgBlockEvent},
+        organization::{
+            Membership, MembershipRole, MembershipState, OrganizationAction,
+            OrganizationEvent,
+        },
+        page_build::{PageBuild, PageBuildEvent, PageBuildStatus},
+        ping::{ContentType, Hook, HookConfig, HookType, PingEvent},
+        project::{Project, ProjectAction, ProjectEvent},
+        project_card::{
+            ColumnChange, ProjectCardAction, ProjectCardChanges, ProjectCardEvent,
+        },
+        project_column::{ProjectColumn, ProjectColumnAction, ProjectColumnEvent},
+        public::PublicEvent,
         pull::{PullRequestAction, PullRequestEvent},
         pull_review::{
             PullRequestReview, PullRequestReviewAction, PullRequestReviewEvent,
@@ -154,11 +553,30 @@ mod test {
             PullRequestReviewComment, PullRequestReviewCommentAction,
             PullRequestReviewCommentEvent,
         },
-        push::PushEvent,
+        push::{FileList, PushEvent},
+        registry_package::{
+            PackageVersion, RegistryPackage, RegistryPackageAction, RegistryPackageEvent,
+        },
         release::{Release, ReleaseAction, ReleaseEvent},
+        repository::{RepositoryAction, RepositoryEvent},
+        repository_dispatch::RepositoryDispatchEvent,
+        repository_vulnerability_alert::{
+            RepositoryVulnerabilityAlertAction, RepositoryVulnerabilityAlertEvent,
+            VulnerabilityAlert, VulnerabilityAlertState,
+        },
+        secret_scanning_alert::{
+            SecretScanningAlert, SecretScanningAlertAction, SecretScanningAlertEvent,
+            SecretValidity,
+        },
         star::{StarAction, StarEvent},
         status::{StatusEvent, StatusState},
+        team::{TeamAction, TeamEvent},
+        team_add::TeamAddEvent,
         watch::{WatchAction, WatchEvent},
+        workflow_dispatch::WorkflowDispatchEvent,
+        workflow_job::{WorkflowJob, WorkflowJobAction, WorkflowJobEvent},
+        workflow_run::{WorkflowRunAction, WorkflowRunEvent},
+        GitHubEvent,
     };
 
     #[test]
@@ -258,6 +676,53 @@ mod test {
         ))
     }
 
+    #[test]
+    fn pull_edited() {
+        let json = include_str!("../../test_json/pull_request_edited.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let pull =
+            serde_path_to_error::deserialize::<_, PullRequestEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(pull.action, PullRequestAction::Edited));
+        assert_eq!(
+            pull.diff().to_string(),
+            "title: 'Update the README' → 'Update the README with new information.', \
+             body: 'A simple change.' → 'This is a pretty simple change that we n…', \
+             base: 'develop' → 'master', \
+             base sha: '6113728f27ae82c7b1a177c8d03f9e96e0adf246' → \
+             'f95f852bd8fca8fcc58a9a2d6c842781e32a215e'"
+        );
+    }
+
+    #[test]
+    fn issue_edited() {
+        let json = include_str!("../../test_json/issue_edited.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let issue = serde_path_to_error::deserialize::<_, IssueEvent<'_>>(jd).unwrap();
+
+        let diff = issue.diff();
+        assert_eq!(
+            diff.fields().iter().map(|f| (f.field, &*f.from)).collect::<Vec<_>>(),
+            [
+                ("title", "Spelling error in the README"),
+                ("body", "You spelled 'commit' wrong.")
+            ]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "title: 'Spelling error in the README' → 'Spelling error in the README file', \
+             body: 'You spelled 'commit' wrong.' → \
+             'It looks like you accidentally spelled '…'"
+        );
+
+        // `issue.json` is an edit that changed nothing
+        let json = include_str!("../../test_json/issue.json");
+        let issue = serde_json::from_str::<IssueEvent<'_>>(json).unwrap();
+        assert!(issue.diff().is_empty());
+    }
+
     #[test]
     fn installation() {
         let json = include_str!("../../test_json/installation.json");
@@ -376,7 +841,7 @@ mod test {
                 organization: None,
                 installation: None,
                 ..
-            } if path == Some(Path::new("hello/world.rs"))
+            } if path.as_deref() == Some(Path::new("hello/world.rs"))
                 && id == uint!(33_548_674)
                 && position == Some(uint!(10))
                 && line == Some(uint!(10))
@@ -409,7 +874,7 @@ mod test {
                 organization: None,
                 installation: None,
                 ..
-            } if body == Some("You are totally right! I'll get this fixed right away.")
+            } if body.as_deref() == Some("You are totally right! I'll get this fixed right away.")
                 && id == uint!(492_700_400)
                 && number == uint!(1)
                 && !sender_urls.is_empty()
@@ -465,7 +930,7 @@ mod test {
                 organization: None,
                 ..
             } if name == "Hello-World"
-                && committer_name == Some("Codertocat")
+                && committer_name.as_deref() == Some("Codertocat")
                 && !created && deleted
                 && !sender_urls.is_empty()
                 && !all_urls.is_empty()
@@ -565,7 +1030,7 @@ mod test {
             MilestoneEvent {
                 action: MilestoneAction::Created,
                 milestone: Milestone { title, number, .. },
-                changes: Some(Changes { .. }),
+                changes: Some(MilestoneChanges { .. }),
                 repository: Repository { all_urls, .. },
                 sender: User { kind: Some(Type::User), all_urls: sender_urls, .. },
                 organization: None,
@@ -578,6 +1043,45 @@ mod test {
         ))
     }
 
+    #[test]
+    fn ping() {
+        let json = include_str!("../../test_json/ping.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let ping = serde_path_to_error::deserialize::<_, PingEvent<'_>>(jd).unwrap();
+
+        assert!(ping.hook.subscribes_to(&EventKind::Push));
+        assert!(!ping.hook.subscribes_to(&EventKind::Star));
+        assert!(matches!(
+            ping,
+            PingEvent {
+                zen,
+                hook_id,
+                hook: Hook {
+                    kind: HookType::Repository,
+                    active: true,
+                    events,
+                    config: HookConfig {
+                        content_type: ContentType::Json,
+                        insecure_ssl: false,
+                        url,
+                        ..
+                    },
+                    all_urls,
+                    ..
+                },
+                repository: Some(Repository { name, .. }),
+                sender: Some(User { kind: Some(Type::User), .. }),
+                organization: None,
+            } if zen == "Anything added dilutes everything else."
+                && hook_id == uint!(30)
+                && name == "Hello-World"
+                && events == [EventKind::Push, EventKind::PullRequest]
+                && url.as_str() == "https://example.com/webhook"
+                && !all_urls.is_empty()
+        ))
+    }
+
     #[test]
     fn watch() {
         let json = include_str!("../../test_json/watch.json");
@@ -598,6 +1102,699 @@ mod test {
         ))
     }
 
+    #[test]
+    fn discussion() {
+        let json = include_str!("../../test_json/discussion.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let discussion =
+            serde_path_to_error::deserialize::<_, DiscussionEvent<'_>>(jd).unwrap();
+
+        assert!(!discussion.discussion.is_answered());
+        assert!(matches!(
+            discussion,
+            DiscussionEvent {
+                action: DiscussionAction::Created,
+                discussion: Discussion {
+                    title,
+                    state: DiscussionState::Open,
+                    category: DiscussionCategory { name, is_answerable: true, .. },
+                    answer_chosen_by: None,
+                    ..
+                },
+                answer: None,
+                changes: None,
+                repository: Repository { full_name, .. },
+                ..
+            } if title == "How do I configure the bot?"
+                && name == "Q&A"
+                && full_name == "Codertocat/Hello-World"
+        ))
+    }
+
+    #[test]
+    fn discussion_answered() {
+        let json = include_str!("../../test_json/discussion_answered.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let discussion =
+            serde_path_to_error::deserialize::<_, DiscussionEvent<'_>>(jd).unwrap();
+
+        assert!(discussion.discussion.is_answered());
+        assert!(matches!(
+            discussion,
+            DiscussionEvent {
+                action: DiscussionAction::Answered,
+                discussion: Discussion {
+                    answer_chosen_at: Some(_),
+                    answer_chosen_by: Some(User { login, .. }),
+                    ..
+                },
+                answer: Some(DiscussionComment { body, parent_id: None, .. }),
+                ..
+            } if login == "Codertocat" && body == "Set `events` in `github.toml`."
+        ))
+    }
+
+    #[test]
+    fn discussion_comment() {
+        let json = include_str!("../../test_json/discussion_comment.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let comment =
+            serde_path_to_error::deserialize::<_, DiscussionCommentEvent<'_>>(jd)
+                .unwrap();
+
+        assert!(matches!(
+            comment,
+            DiscussionCommentEvent {
+                action: DiscussionCommentAction::Created,
+                comment: DiscussionComment { body, user: User { login, .. }, .. },
+                discussion: Discussion { number, comments, .. },
+                ..
+            } if body == "Set `events` in `github.toml`."
+                && login == "octocat"
+                && number == uint!(90)
+                && comments == uint!(1)
+        ))
+    }
+
+    #[test]
+    fn label() {
+        let json = include_str!("../../test_json/label.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let label = serde_path_to_error::deserialize::<_, LabelEvent<'_>>(jd).unwrap();
+
+        assert_eq!(
+            label.diff().to_string(),
+            "name: 'bug' → 'bugfix', color: 'd73a4a' → 'cfd3d7'"
+        );
+
+        assert!(matches!(
+            label,
+            LabelEvent {
+                action: LabelAction::Edited,
+                label: Label { name, description: None, .. },
+                changes: Some(LabelChanges { name: Some(old_name), color: Some(old_color), .. }),
+                repository: Repository { full_name, .. },
+                organization: None,
+                installation: None,
+                ..
+            } if name == "bugfix"
+                && old_name.from == "bug"
+                && old_color.from == "d73a4a"
+                && full_name == "Codertocat/Hello-World"
+        ))
+    }
+
+    #[test]
+    fn member() {
+        let json = include_str!("../../test_json/member.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let member = serde_path_to_error::deserialize::<_, MemberEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            member,
+            MemberEvent {
+                action: MemberAction::Added,
+                member: User { login, .. },
+                changes: Some(Changes { permission: Some(permission), .. }),
+                sender: User { login: sender, .. },
+                ..
+            } if login == "octocat"
+                && sender == "Codertocat"
+                && permission.from.is_none()
+                && permission.to.as_deref() == Some("write")
+        ))
+    }
+
+    #[test]
+    fn fork() {
+        let json = include_str!("../../test_json/fork.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let fork = serde_path_to_error::deserialize::<_, ForkEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            fork,
+            ForkEvent {
+                forkee: Repository { full_name: fork_name, fork: true, .. },
+                repository: Repository { full_name, fork: false, .. },
+                ..
+            } if fork_name == "Octocoders/Hello-World"
+                && full_name == "Codertocat/Hello-World"
+        ))
+    }
+
+    #[test]
+    fn public() {
+        let json = include_str!("../../test_json/public.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let public = serde_path_to_error::deserialize::<_, PublicEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            public,
+            PublicEvent {
+                repository: Repository { full_name, .. },
+                organization: None,
+                installation: None,
+                ..
+            } if full_name == "Codertocat/Hello-World"
+        ))
+    }
+
+    #[test]
+    fn repository() {
+        let json = include_str!("../../test_json/repository.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let repo =
+            serde_path_to_error::deserialize::<_, RepositoryEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            repo,
+            RepositoryEvent {
+                action: RepositoryAction::Renamed,
+                changes: Some(Changes { repository: Some(changes), .. }),
+                repository: Repository { name, .. },
+                ..
+            } if name == "Hello-World"
+                && changes.name.as_ref().map(|n| &*n.from) == Some("Hello-Wrld")
+        ))
+    }
+
+    #[test]
+    fn organization() {
+        let json = include_str!("../../test_json/organization.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let org =
+            serde_path_to_error::deserialize::<_, OrganizationEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            org,
+            OrganizationEvent {
+                action: OrganizationAction::MemberAdded,
+                membership: Some(Membership {
+                    state: MembershipState::Active,
+                    role: MembershipRole::Member,
+                    user: User { login, .. },
+                    ..
+                }),
+                invitation: None,
+                organization: SimpleOrg { login: org_login, description, .. },
+                ..
+            } if login == "octocat"
+                && org_login == "Octocoders"
+                && description.as_deref() == Some("")
+        ))
+    }
+
+    #[test]
+    fn team() {
+        let json = include_str!("../../test_json/team.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let team = serde_path_to_error::deserialize::<_, TeamEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            team,
+            TeamEvent {
+                action: TeamAction::AddedToRepository,
+                team: Team { slug, parent: None, .. },
+                repository: Some(Repository { name, .. }),
+                organization: SimpleOrg { login, .. },
+                changes: None,
+                ..
+            } if slug == "github" && name == "Hello-World" && login == "Octocoders"
+        ))
+    }
+
+    #[test]
+    fn workflow_run() {
+        let json = include_str!("../../test_json/workflow_run.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let run =
+            serde_path_to_error::deserialize::<_, WorkflowRunEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            run,
+            WorkflowRunEvent {
+                action: WorkflowRunAction::Completed,
+                workflow_run: WorkflowRun {
+                    status: CheckStatus::Completed,
+                    conclusion: Some(ConclusionStatus::Failure),
+                    head_branch,
+                    pull_requests,
+                    actor: Some(User { login, .. }),
+                    ..
+                },
+                workflow: Workflow { path, .. },
+                repository: Repository { name, .. },
+                organization: None,
+                installation: None,
+                ..
+            } if head_branch == "changes"
+                && login == "Codertocat"
+                && path == ".github/workflows/build.yml"
+                && name == "Hello-World"
+                && pull_requests.len() == 1
+                && pull_requests[0].number == uint!(2)
+                && pull_requests[0].base.ref_ == "master"
+        ))
+    }
+
+    #[test]
+    fn workflow_job() {
+        let json = include_str!("../../test_json/workflow_job.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let job =
+            serde_path_to_error::deserialize::<_, WorkflowJobEvent<'_>>(jd).unwrap();
+
+        assert_eq!(job.workflow_job.failed_step().unwrap().name, "Run cargo test");
+        assert!(matches!(
+            job,
+            WorkflowJobEvent {
+                action: WorkflowJobAction::Completed,
+                workflow_job: WorkflowJob {
+                    run_id,
+                    name,
+                    status: CheckStatus::Completed,
+                    conclusion: Some(ConclusionStatus::Failure),
+                    completed_at: Some(_),
+                    steps,
+                    labels,
+                    ..
+                },
+                repository: Repository { name: repo_name, .. },
+                organization: None,
+                installation: None,
+                ..
+            } if name == "test"
+                && run_id == uint!(30_433_642)
+                && repo_name == "Hello-World"
+                && labels == ["ubuntu-latest"]
+                && steps.len() == 4
+                && matches!(steps[3].conclusion, Some(ConclusionStatus::Skipped))
+                && steps[3].started_at.is_none()
+        ))
+    }
+
+    #[test]
+    fn code_scanning_alert() {
+        let json = include_str!("../../test_json/code_scanning_alert.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let alert = serde_path_to_error::deserialize::<_, CodeScanningAlertEvent<'_>>(jd)
+            .unwrap();
+
+        assert!(alert.alert.is_open());
+        assert!(matches!(
+            alert,
+            CodeScanningAlertEvent {
+                action: CodeScanningAlertAction::Created,
+                alert: CodeScanningAlert {
+                    number,
+                    state: CodeScanningAlertState::Open,
+                    rule: CodeScanningRule {
+                        id,
+                        severity: Some(RuleSeverity::Warning),
+                        security_severity_level: None,
+                        tags,
+                        ..
+                    },
+                    most_recent_instance: Some(CodeScanningAlertInstance {
+                        location: Some(CodeScanningLocation { path, start_line, .. }),
+                        ..
+                    }),
+                    ..
+                },
+                ref_,
+                sender: User { login, .. },
+                ..
+            } if number == uint!(10)
+                && id == "js/unused-local-variable"
+                && tags.len() == 2
+                && path == "src/index.js"
+                && start_line == uint!(12)
+                && ref_ == "refs/heads/master"
+                && login == "github"
+        ))
+    }
+
+    #[test]
+    fn secret_scanning_alert() {
+        let json = include_str!("../../test_json/secret_scanning_alert.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let alert =
+            serde_path_to_error::deserialize::<_, SecretScanningAlertEvent<'_>>(jd)
+                .unwrap();
+
+        assert!(alert.alert.is_open());
+        assert!(matches!(
+            alert,
+            SecretScanningAlertEvent {
+                action: SecretScanningAlertAction::Created,
+                alert: SecretScanningAlert {
+                    number,
+                    secret_type,
+                    resolution: None,
+                    validity: Some(SecretValidity::Unknown),
+                    locations_url: Some(_),
+                    ..
+                },
+                repository: Repository { name, .. },
+                sender: None,
+                ..
+            } if number == uint!(3) && secret_type == "adafruit_io_key" && name == "Hello-World"
+        ))
+    }
+
+    #[test]
+    fn dependabot_alert() {
+        let json = include_str!("../../test_json/dependabot_alert.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let alert =
+            serde_path_to_error::deserialize::<_, DependabotAlertEvent<'_>>(jd).unwrap();
+
+        assert!(alert.alert.is_open());
+        assert_eq!(alert.alert.dependency.package.name, "smallvec");
+        assert!(matches!(
+            alert,
+            DependabotAlertEvent {
+                action: DependabotAlertAction::Created,
+                alert: DependabotAlert {
+                    state: DependabotAlertState::Open,
+                    security_advisory: SecurityAdvisory {
+                        ghsa_id,
+                        cve_id: Some(cve_id),
+                        severity: AlertSeverity::High,
+                        ..
+                    },
+                    security_vulnerability: SecurityVulnerability {
+                        first_patched_version: Some(patched),
+                        ..
+                    },
+                    ..
+                },
+                ..
+            } if ghsa_id == "GHSA-66p5-j55p-32r9"
+                && cve_id == "CVE-2021-25900"
+                && patched.identifier == "0.6.14"
+        ))
+    }
+
+    #[test]
+    fn repository_vulnerability_alert() {
+        let json = include_str!("../../test_json/repository_vulnerability_alert.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let alert = serde_path_to_error::deserialize::<
+            _,
+            RepositoryVulnerabilityAlertEvent<'_>,
+        >(jd)
+        .unwrap();
+
+        assert!(matches!(
+            alert,
+            RepositoryVulnerabilityAlertEvent {
+                action: RepositoryVulnerabilityAlertAction::Dismiss,
+                alert: VulnerabilityAlert {
+                    state: VulnerabilityAlertState::Dismissed,
+                    severity: AlertSeverity::Moderate,
+                    affected_package_name,
+                    dismisser: Some(User { login, .. }),
+                    dismissed_at: Some(_),
+                    ..
+                },
+                ..
+            } if affected_package_name == "smallvec" && login == "Codertocat"
+        ))
+    }
+
+    #[test]
+    fn project() {
+        let json = include_str!("../../test_json/project.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let project =
+            serde_path_to_error::deserialize::<_, ProjectEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            project,
+            ProjectEvent {
+                action: ProjectAction::Edited,
+                project: Project { name, state: IssueState::Open, .. },
+                changes: Some(Changes { name: Some(old), .. }),
+                repository: Some(_),
+                ..
+            } if name == "Roadmap" && old.from == "Planning"
+        ))
+    }
+
+    #[test]
+    fn project_card() {
+        let json = include_str!("../../test_json/project_card.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let card =
+            serde_path_to_error::deserialize::<_, ProjectCardEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            card,
+            ProjectCardEvent {
+                action: ProjectCardAction::Moved,
+                changes: Some(ProjectCardChanges {
+                    column_id: Some(ColumnChange { from }),
+                    note: None,
+                }),
+                after_id: Some(after_id),
+                ..
+            } if from == uint!(5_368_156) && after_id == uint!(21_567_452)
+        ))
+    }
+
+    #[test]
+    fn project_column() {
+        let json = include_str!("../../test_json/project_column.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let column =
+            serde_path_to_error::deserialize::<_, ProjectColumnEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            column,
+            ProjectColumnEvent {
+                action: ProjectColumnAction::Created,
+                project_column: ProjectColumn { name, .. },
+                changes: None,
+                ..
+            } if name == "Small bugfixes"
+        ))
+    }
+
+    #[test]
+    fn gollum() {
+        let json = include_str!("../../test_json/gollum.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let gollum = serde_path_to_error::deserialize::<_, GollumEvent<'_>>(jd).unwrap();
+
+        assert_eq!(gollum.pages.len(), 2);
+        assert!(matches!(gollum.pages[0].action, WikiPageAction::Created));
+        assert!(matches!(gollum.pages[1].action, WikiPageAction::Edited));
+        assert_eq!(gollum.pages[1].summary.as_deref(), Some("Add install steps"));
+    }
+
+    #[test]
+    fn page_build() {
+        let json = include_str!("../../test_json/page_build.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let build =
+            serde_path_to_error::deserialize::<_, PageBuildEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            build,
+            PageBuildEvent {
+                build: PageBuild {
+                    status: PageBuildStatus::Errored,
+                    error,
+                    duration,
+                    ..
+                },
+                ..
+            } if error.message.as_deref() == Some("Page build failed.")
+                && duration == uint!(16_984)
+        ))
+    }
+
+    #[test]
+    fn registry_package() {
+        let json = include_str!("../../test_json/registry_package.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let package =
+            serde_path_to_error::deserialize::<_, RegistryPackageEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            package,
+            RegistryPackageEvent {
+                action: RegistryPackageAction::Published,
+                registry_package: RegistryPackage {
+                    name,
+                    package_version: Some(PackageVersion { version, .. }),
+                    registry: Some(_),
+                    ..
+                },
+                ..
+            } if name == "hello-world" && version == "1.0.2"
+        ))
+    }
+
+    #[test]
+    fn repository_dispatch() {
+        let json = include_str!("../../test_json/repository_dispatch.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let dispatch =
+            serde_path_to_error::deserialize::<_, RepositoryDispatchEvent<'_>>(jd)
+                .unwrap();
+
+        assert_eq!(dispatch.action, "deploy");
+        assert_eq!(dispatch.branch, "master");
+        assert_eq!(dispatch.client_payload.unwrap()["environment"], "staging");
+    }
+
+    #[test]
+    fn workflow_dispatch() {
+        let json = include_str!("../../test_json/workflow_dispatch.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let dispatch =
+            serde_path_to_error::deserialize::<_, WorkflowDispatchEvent<'_>>(jd).unwrap();
+
+        assert_eq!(dispatch.ref_, "refs/heads/master");
+        assert_eq!(dispatch.workflow, ".github/workflows/release.yml");
+        assert_eq!(dispatch.inputs.unwrap()["name"], "Mona the Octocat");
+    }
+
+    #[test]
+    fn content_reference() {
+        let json = include_str!("../../test_json/content_reference.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let reference =
+            serde_path_to_error::deserialize::<_, ContentReferenceEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(reference.action, ContentReferenceAction::Created));
+        assert_eq!(reference.content_reference.reference, "https://errors.ai/");
+    }
+
+    #[test]
+    fn deploy_key() {
+        let json = include_str!("../../test_json/deploy_key.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let key = serde_path_to_error::deserialize::<_, DeployKeyEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            key,
+            DeployKeyEvent {
+                action: DeployKeyAction::Created,
+                key: DeployKey { title, read_only: true, verified: true, .. },
+                ..
+            } if title == "deploy@ci"
+        ))
+    }
+
+    #[test]
+    fn deployment_review() {
+        let json = include_str!("../../test_json/deployment_review.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let review =
+            serde_path_to_error::deserialize::<_, DeploymentReviewEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(review.action, DeploymentReviewAction::Requested));
+        assert_eq!(review.environment.as_deref(), Some("production"));
+        assert!(matches!(review.reviewers[0].kind, ReviewerType::User));
+        assert!(matches!(
+            &review.reviewers[0].reviewer,
+            Reviewer::User(user) if user.login == "Codertocat"
+        ));
+        assert!(matches!(review.reviewers[1].kind, ReviewerType::Team));
+        assert!(matches!(
+            &review.reviewers[1].reviewer,
+            Reviewer::Team(team) if team.slug == "github"
+        ));
+        assert_eq!(review.workflow_job_runs.len(), 1);
+        assert!(review.workflow_run.is_some());
+    }
+
+    #[test]
+    fn membership() {
+        let json = include_str!("../../test_json/membership.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let membership =
+            serde_path_to_error::deserialize::<_, MembershipEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            membership,
+            MembershipEvent {
+                action: MembershipAction::Added,
+                scope: MembershipScope::Team,
+                member: User { login, .. },
+                team: Team { name, .. },
+                ..
+            } if login == "Codertocat" && name == "github"
+        ))
+    }
+
+    #[test]
+    fn org_block() {
+        let json = include_str!("../../test_json/org_block.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let block = serde_path_to_error::deserialize::<_, OrgBlockEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            block,
+            OrgBlockEvent {
+                action: OrgBlockAction::Blocked,
+                blocked_user: User { login, .. },
+                organization: SimpleOrg { login: org, .. },
+                ..
+            } if login == "Codertocat" && org == "Octocoders"
+        ))
+    }
+
+    #[test]
+    fn team_add() {
+        let json = include_str!("../../test_json/team_add.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let add = serde_path_to_error::deserialize::<_, TeamAddEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            add,
+            TeamAddEvent {
+                team: Team { name, .. },
+                repository: Repository { name: repo_name, .. },
+                organization: Some(_),
+                ..
+            } if name == "github" && repo_name == "Hello-World"
+        ))
+    }
+
     #[test]
     fn pull_request_review_comment() {
         let json = include_str!("../../test_json/pull_review_comment.json");
@@ -642,7 +1839,7 @@ mod test {
                 organization: None,
                 ..
             } if name == "magit"
-                && committer_name == Some("DevinR528")
+                && committer_name.as_deref() == Some("DevinR528")
                 && !created && !deleted && !forced
                 && !sender_urls.is_empty()
                 && !all_urls.is_empty()
@@ -681,4 +1878,291 @@ mod test {
                 && !pull_requests.is_empty()
         ))
     }
+
+    #[test]
+    fn deployment() {
+        let json = include_str!("../../test_json/deployment.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let deployment =
+            serde_path_to_error::deserialize::<_, DeploymentEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            deployment,
+            DeploymentEvent {
+                action: DeploymentAction::Created,
+                deployment: Deployment { id, ref_: Some(ref_), environment, creator: Some(_), .. },
+                repository: Repository { all_urls, .. },
+                sender: User { kind: Some(Type::User), all_urls: sender_urls, .. },
+                organization: None,
+                installation: None,
+            } if id == uint!(326_191_728)
+                && ref_ == "main"
+                && environment == "production"
+                && !sender_urls.is_empty()
+                && !all_urls.is_empty()
+        ))
+    }
+
+    #[test]
+    fn deployment_status() {
+        let json = include_str!("../../test_json/deployment_status.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let status =
+            serde_path_to_error::deserialize::<_, DeploymentStatusEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            status,
+            DeploymentStatusEvent {
+                action: DeploymentStatusAction::Created,
+                deployment_status: DeploymentStatus {
+                    state: DeploymentState::Success,
+                    environment_url: Some(environment_url),
+                    ..
+                },
+                deployment: Deployment { id, .. },
+                repository: Repository { all_urls, .. },
+                sender: User { kind: Some(Type::User), all_urls: sender_urls, .. },
+                organization: None,
+                installation: None,
+            } if id == uint!(326_191_728)
+                && environment_url == "https://hello-world.example.com"
+                && !sender_urls.is_empty()
+                && !all_urls.is_empty()
+        ))
+    }
+
+    #[test]
+    fn from_raw() {
+        let json = include_str!("../../test_json/star.json");
+
+        let event = GitHubEvent::from_raw("star", json).unwrap();
+        assert!(matches!(
+            event,
+            GitHubEvent::Star(StarEvent { action: StarAction::Created, .. })
+        ));
+        assert_eq!(event.as_kind().as_ref(), "star");
+
+        // Events without a payload type are still parsed.
+        let event = GitHubEvent::from_raw("not_an_event", json).unwrap();
+        assert!(matches!(
+            &event,
+            GitHubEvent::Unknown { kind: EventKind::_Custom(kind), payload }
+                if kind == "not_an_event" && payload["action"] == "created"
+        ));
+        assert_eq!(event.as_kind().as_ref(), "not_an_event");
+        assert!(GitHubEvent::from_raw("not_an_event", "not json").is_err());
+
+        // A star payload is not a valid push payload.
+        let err = GitHubEvent::from_raw("push", json).unwrap_err();
+        assert!(err.path().is_some());
+    }
+
+    #[test]
+    fn unknown_action() {
+        let json = include_str!("../../test_json/star.json")
+            .replace(r#""action": "created""#, r#""action": "reacted""#);
+
+        let jd = &mut serde_json::Deserializer::from_str(&json);
+        let star = serde_path_to_error::deserialize::<_, StarEvent<'_>>(jd).unwrap();
+
+        assert!(
+            matches!(&star.action, StarAction::_Custom(action) if action == "reacted")
+        );
+        assert_eq!(star.action.as_ref(), "reacted");
+        assert_eq!(serde_json::to_value(&star).unwrap()["action"], "reacted");
+    }
+
+    #[test]
+    fn from_raw_error_path() {
+        let json = r#"{ "action": "created", "starred_at": "now", "repository": {} }"#;
+
+        let err = GitHubEvent::from_raw("star", json).unwrap_err();
+        assert_eq!(err.path().unwrap().to_string(), "starred_at");
+    }
+
+    #[test]
+    fn issue_escaped() {
+        let json = include_str!("../../test_json/issue_escaped.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let issue = serde_path_to_error::deserialize::<_, IssueEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            issue,
+            IssueEvent {
+                issue: Issue { title, body, .. },
+                ..
+            } if title == "Crash when the \"name\" field is empty"
+                && body.starts_with("Steps to reproduce:\r\n\r\n1. Open `C:\\Users\\octocat`")
+                && body.contains('\u{1F680}')
+        ))
+    }
+
+    #[test]
+    fn issue_comment_escaped() {
+        let json = include_str!("../../test_json/issue_comment_escaped.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let issue_comment =
+            serde_path_to_error::deserialize::<_, IssueCommentEvent<'_>>(jd).unwrap();
+
+        assert!(matches!(
+            issue_comment,
+            IssueCommentEvent {
+                comment: Comment { body: Some(body), .. },
+                ..
+            } if body.lines().count() == 6
+                && body.contains(r#"let s = "quoted \"string\"";"#)
+                && body.ends_with("\u{e9}t\u{e9} \u{1F389}")
+        ))
+    }
+
+    #[test]
+    fn push_escaped() {
+        let json = include_str!("../../test_json/push_escaped.json");
+
+        let jd = &mut serde_json::Deserializer::from_str(json);
+        let push = serde_path_to_error::deserialize::<_, PushEvent<'_>>(jd).unwrap();
+
+        assert_eq!(
+            push.commits[0].message,
+            "Fix \"escaped\" commit messages\n\nThe body has a tab\there and a path C:\\tmp \u{2714}"
+        );
+        // Payloads without escapes are still borrowed from the request body.
+        assert!(matches!(push.repository.name, std::borrow::Cow::Borrowed("magit")));
+
+        let added = push.commits[0].added.paths().unwrap();
+        assert_eq!(added, [".cargo/config", "test_json/check.json"]);
+        assert!(matches!(added[0], std::borrow::Cow::Borrowed(_)));
+        assert!(push.commits[0].removed.is_empty());
+        assert!(!push.commits[0].modified.is_empty());
+    }
+
+    #[test]
+    fn file_list() {
+        let files: FileList<'_> =
+            serde_json::from_str(r#"[ "C:\\tmp", "a.rs" ]"#).unwrap();
+        assert!(!files.is_empty());
+        assert_eq!(files.paths().unwrap(), ["C:\\tmp", "a.rs"]);
+        // Kept as github sent it
+        assert_eq!(serde_json::to_string(&files).unwrap(), r#"[ "C:\\tmp", "a.rs" ]"#);
+
+        let files: FileList<'_> = serde_json::from_str("[ ]").unwrap();
+        assert!(files.is_empty());
+        assert!(FileList::default().paths().unwrap().is_empty());
+        let files: FileList<'_> = serde_json::from_str("[[]]").unwrap();
+        assert!(!files.is_empty());
+
+        let files: FileList<'_> = serde_json::from_str("[1]").unwrap();
+        assert!(files.paths().is_err());
+    }
+
+    #[test]
+    fn into_owned() {
+        let json = include_str!("../../test_json/issue_escaped.json").to_owned();
+        let event = GitHubEvent::from_raw_lenient("issues", &json).unwrap().into_owned();
+        drop(json);
+
+        // The owned event outlives the body and can be moved to another thread.
+        let event = std::thread::spawn(move || event).join().unwrap();
+        assert!(matches!(
+            event,
+            GitHubEvent::Issue(IssueEvent {
+                issue: Issue { title, .. },
+                sender: User { login, .. },
+                ..
+            }) if title == "Crash when the \"name\" field is empty"
+                && matches!(login, std::borrow::Cow::Owned(_))
+        ))
+    }
+
+    #[test]
+    fn serialize_replay() {
+        for (kind, json) in &[
+            ("check_run", include_str!("../../test_json/check_run.json")),
+            ("check_run", include_str!("../../test_json/real_check_run.json")),
+            ("check_suite", include_str!("../../test_json/check_suite.json")),
+            (
+                "code_scanning_alert",
+                include_str!("../../test_json/code_scanning_alert.json"),
+            ),
+            ("commit_comment", include_str!("../../test_json/commit_comment.json")),
+            ("content_reference", include_str!("../../test_json/content_reference.json")),
+            ("create", include_str!("../../test_json/create.json")),
+            ("delete", include_str!("../../test_json/delete.json")),
+            ("dependabot_alert", include_str!("../../test_json/dependabot_alert.json")),
+            ("deploy_key", include_str!("../../test_json/deploy_key.json")),
+            ("deployment", include_str!("../../test_json/deployment.json")),
+            ("deployment_review", include_str!("../../test_json/deployment_review.json")),
+            ("deployment_status", include_str!("../../test_json/deployment_status.json")),
+            ("discussion", include_str!("../../test_json/discussion.json")),
+            ("discussion", include_str!("../../test_json/discussion_answered.json")),
+            (
+                "discussion_comment",
+                include_str!("../../test_json/discussion_comment.json"),
+            ),
+            ("fork", include_str!("../../test_json/fork.json")),
+            ("gollum", include_str!("../../test_json/gollum.json")),
+            ("installation", include_str!("../../test_json/installation.json")),
+            ("issue_comment", include_str!("../../test_json/issue_comment.json")),
+            ("issue_comment", include_str!("../../test_json/issue_comment_escaped.json")),
+            ("issues", include_str!("../../test_json/issue.json")),
+            ("issues", include_str!("../../test_json/issue_escaped.json")),
+            ("label", include_str!("../../test_json/label.json")),
+            ("member", include_str!("../../test_json/member.json")),
+            ("membership", include_str!("../../test_json/membership.json")),
+            ("milestone", include_str!("../../test_json/milestone.json")),
+            ("org_block", include_str!("../../test_json/org_block.json")),
+            ("organization", include_str!("../../test_json/organization.json")),
+            ("page_build", include_str!("../../test_json/page_build.json")),
+            ("ping", include_str!("../../test_json/ping.json")),
+            ("project", include_str!("../../test_json/project.json")),
+            ("project_card", include_str!("../../test_json/project_card.json")),
+            ("project_column", include_str!("../../test_json/project_column.json")),
+            ("public", include_str!("../../test_json/public.json")),
+            ("pull_request", include_str!("../../test_json/pull_request.json")),
+            ("pull_request", include_str!("../../test_json/real_pull.json")),
+            ("pull_request_review", include_str!("../../test_json/pull_review.json")),
+            (
+                "pull_request_review_comment",
+                include_str!("../../test_json/pull_review_comment.json"),
+            ),
+            ("push", include_str!("../../test_json/push.json")),
+            ("push", include_str!("../../test_json/real_push.json")),
+            ("push", include_str!("../../test_json/push_escaped.json")),
+            ("registry_package", include_str!("../../test_json/registry_package.json")),
+            ("release", include_str!("../../test_json/release.json")),
+            ("repository", include_str!("../../test_json/repository.json")),
+            (
+                "repository_dispatch",
+                include_str!("../../test_json/repository_dispatch.json"),
+            ),
+            (
+                "repository_vulnerability_alert",
+                include_str!("../../test_json/repository_vulnerability_alert.json"),
+            ),
+            (
+                "secret_scanning_alert",
+                include_str!("../../test_json/secret_scanning_alert.json"),
+            ),
+            ("star", include_str!("../../test_json/star.json")),
+            ("status", include_str!("../../test_json/status.json")),
+            ("team", include_str!("../../test_json/team.json")),
+            ("team_add", include_str!("../../test_json/team_add.json")),
+            ("watch", include_str!("../../test_json/watch.json")),
+            ("workflow_dispatch", include_str!("../../test_json/workflow_dispatch.json")),
+            ("workflow_job", include_str!("../../test_json/workflow_job.json")),
+            ("workflow_run", include_str!("../../test_json/workflow_run.json")),
+        ] {
+            // The fixtures have keys no field captures, what is stored never does
+            let event = GitHubEvent::from_raw_lenient(kind, json).unwrap();
+            let stored = serde_json::to_string(&event).unwrap();
+
+            let replayed = GitHubEvent::from_raw(event.as_kind().as_ref(), &stored)
+                .unwrap_or_else(|e| panic!("{} failed to replay: {}", kind, e));
+            assert_eq!(replayed.as_kind(), event.as_kind());
+        }
+    }
 }
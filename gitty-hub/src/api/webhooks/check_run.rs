@@ -1,15 +1,23 @@
+use std::borrow::Cow;
+
+use github_derive::IntoOwned;
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{
-    datetime, datetime_opt, default_null,
-    webhooks::check_suite::{CheckAction, CheckPullRequest, CheckSuite},
-    App, CheckStatus, ConclusionStatus, Dt, Installation, Org, Repository, User,
+pub use crate::api::Deployment;
+use crate::{
+    api::{
+        default_null, null_default,
+        webhooks::check_suite::{CheckAction, CheckPullRequest, CheckSuite},
+        App, CheckStatus, ConclusionStatus, Installation, Repository, SimpleOrg,
+        Timestamp, User,
+    },
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
 };
 
 /// The payload of a check run event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CheckRunEvent<'a> {
     /// The action that was performed.
     pub action: CheckAction,
@@ -24,7 +32,7 @@ pub struct CheckRunEvent<'a> {
 
     /// Detailed information about the organization the app
     /// belongs to.
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -37,41 +45,44 @@ pub struct CheckRunEvent<'a> {
 }
 
 /// Information about a suite of checks.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CheckRun<'a> {
     /// Numeric Id of this installation.
     pub id: UInt,
 
     /// Numeric identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The SHA of the head branch.
-    pub head_sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub head_sha: Cow<'a, str>,
 
     /// The api url of the pull request.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// A reference for the run on the integrators system.
-    pub external_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub external_id: Cow<'a, str>,
 
     /// The api url of the pull request.
-    pub details_url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub details_url: Cow<'a, str>,
 
     /// The status of this check.
     #[serde(default, deserialize_with = "default_null")]
     pub status: CheckStatus,
 
     /// If this is not none then the check has finished with a status.
-    #[serde(default, deserialize_with = "default_null")]
+    #[serde(default, deserialize_with = "default_null", serialize_with = "null_default")]
     pub conclusion: ConclusionStatus,
 
     /// The time in UTC when the check run was started.
-    #[serde(deserialize_with = "datetime")]
-    pub started_at: Dt,
+    pub started_at: Timestamp,
 
     /// The time in UTC when the check run was completed.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub completed_at: Option<Dt>,
+    pub completed_at: Option<Timestamp>,
 
     /// The pull request being checked.
     #[serde(borrow)]
@@ -95,16 +106,19 @@ pub struct CheckRun<'a> {
 }
 
 /// The output from a check.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct Output<'a> {
     /// Title of this check run.
-    pub title: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub title: Option<Cow<'a, str>>,
 
     /// Summary of the check.
-    pub summary: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub summary: Option<Cow<'a, str>>,
 
     /// The api url of the pull request.
-    pub text: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub text: Option<Cow<'a, str>>,
 
     /// Number of annotations on this check.
     pub annotations_count: UInt,
@@ -112,36 +126,3 @@ pub struct Output<'a> {
     /// The api url of the annotations.
     pub annotations_url: Url,
 }
-
-/// Information about the deployment of this check.
-#[derive(Clone, Debug, Deserialize)]
-pub struct Deployment<'a> {
-    /// The api url of the check run.
-    pub url: Url,
-
-    /// Numeric Id of this deployment.
-    pub id: UInt,
-
-    /// Numeric identifier of the deployment.
-    pub node_id: &'a str,
-
-    /// The task being run.
-    pub task: &'a str,
-
-    /// The current environment for this check.
-    pub environment: &'a str,
-
-    /// The original environment for this check.
-    pub original_environment: &'a str,
-
-    /// The api url of the check run.
-    pub description: Option<&'a str>,
-
-    /// The time in UTC when the check run was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
-
-    /// The time in UTC when the check run was updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
-}
@@ -1,15 +1,20 @@
-use github_derive::StringEnum;
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{
-    datetime, default_null, App, CheckStatus, ConclusionStatus, Dt, Installation, Org,
-    Repository, SimpleCommit, User,
+use crate::{
+    api::{
+        default_null, null_default, App, CheckStatus, ConclusionStatus, Installation,
+        Repository, SimpleCommit, SimpleOrg, Timestamp, User,
+    },
+    utils::deserialize_cow_str,
 };
 
 /// The payload of a check suite event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CheckSuiteEvent<'a> {
     /// The action that was performed.
     pub action: CheckAction,
@@ -25,7 +30,7 @@ pub struct CheckSuiteEvent<'a> {
     /// Detailed information about the organization the app
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -61,36 +66,44 @@ pub enum CheckAction {
 
     /// Someone requested an action your app provides to be taken.
     RequestedAction,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// Information about a suite of checks.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CheckSuite<'a> {
     /// Numeric Id of this installation.
     pub id: UInt,
 
     /// Numeric identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// Name of the head branch.
-    pub head_branch: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub head_branch: Cow<'a, str>,
 
     /// The SHA of the head branch.
-    pub head_sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub head_sha: Cow<'a, str>,
 
     /// The status of this check.
     #[serde(default, deserialize_with = "default_null")]
     pub status: CheckStatus,
 
     /// If this is not none then the check has finished with a status.
-    #[serde(default, deserialize_with = "default_null")]
+    #[serde(default, deserialize_with = "default_null", serialize_with = "null_default")]
     pub conclusion: ConclusionStatus,
 
     /// The SHA of the branch before.
-    pub before: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub before: Cow<'a, str>,
 
     /// The SHA of the branch after.
-    pub after: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub after: Cow<'a, str>,
 
     /// The pull request being checked.
     pub pull_requests: Vec<CheckPullRequest<'a>>,
@@ -110,16 +123,14 @@ pub struct CheckSuite<'a> {
     pub head_commit: Option<SimpleCommit<'a>>,
 
     /// The time in UTC when the check was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// The time in UTC when the check was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 }
 
 /// Information about pull requests being checked.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CheckPullRequest<'a> {
     /// The github API url of the pull request.
     pub url: Url,
@@ -140,14 +151,16 @@ pub struct CheckPullRequest<'a> {
 }
 
 /// Information about the head.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct HeadRef<'a> {
     /// The github API url of the head.
     #[serde(rename = "ref")]
-    pub ref_: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
 
     /// The SHA of this head.
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 
     /// Information about the related head.
     #[serde(borrow)]
@@ -155,14 +168,16 @@ pub struct HeadRef<'a> {
 }
 
 /// Information about the base.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct BaseRef<'a> {
     /// The github API url of the base.
     #[serde(rename = "ref")]
-    pub ref_: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
 
     /// The SHA of this base.
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 
     /// Information about the related base.
     #[serde(borrow)]
@@ -170,7 +185,7 @@ pub struct BaseRef<'a> {
 }
 
 /// Information about the repository.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct RepoRef<'a> {
     /// Numeric Id of this repository.
     pub id: UInt,
@@ -179,5 +194,6 @@ pub struct RepoRef<'a> {
     pub url: Url,
 
     /// The name of this repository.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 }
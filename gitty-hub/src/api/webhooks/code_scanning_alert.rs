@@ -0,0 +1,276 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{AlertSeverity, Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a code scanning alert event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CodeScanningAlertEvent<'a> {
+    /// The action that was performed.
+    pub action: CodeScanningAlertAction,
+
+    /// The code scanning alert.
+    #[serde(borrow)]
+    pub alert: CodeScanningAlert<'a>,
+
+    /// The git reference of the code scanning alert.
+    ///
+    /// This is empty when the action is `reopened_by_user` or `closed_by_user`.
+    #[serde(rename = "ref")]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
+
+    /// The SHA of the commit the alert was raised on.
+    ///
+    /// This is empty when the action is `reopened_by_user` or `closed_by_user`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub commit_oid: Cow<'a, str>,
+
+    /// Detailed information about the repository the alert belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the event.
+    ///
+    /// This is the `github` bot when the alert was changed by a scan.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a code scanning alert event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CodeScanningAlertAction {
+    /// A code scanning alert was created in a repository.
+    Created,
+
+    /// A previously created alert appeared in another branch.
+    AppearedInBranch,
+
+    /// A previously fixed alert reappeared in a scan.
+    Reopened,
+
+    /// A code scanning alert was fixed by a commit.
+    Fixed,
+
+    /// Someone closed a code scanning alert.
+    ClosedByUser,
+
+    /// Someone reopened a code scanning alert.
+    ReopenedByUser,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a code scanning alert.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CodeScanningAlert<'a> {
+    /// The number of this alert within the repository.
+    pub number: UInt,
+
+    /// The state of this alert.
+    pub state: CodeScanningAlertState,
+
+    /// The rule that raised this alert.
+    #[serde(borrow)]
+    pub rule: CodeScanningRule<'a>,
+
+    /// The tool that found the problem.
+    #[serde(borrow)]
+    pub tool: CodeScanningTool<'a>,
+
+    /// The most recent place the problem was found.
+    #[serde(borrow)]
+    pub most_recent_instance: Option<CodeScanningAlertInstance<'a>>,
+
+    /// The user who dismissed the alert.
+    #[serde(borrow)]
+    pub dismissed_by: Option<User<'a>>,
+
+    /// The reason the alert was dismissed, ie. `false positive` or `won't fix`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub dismissed_reason: Option<Cow<'a, str>>,
+
+    /// The time in UTC when the alert was dismissed.
+    pub dismissed_at: Option<Timestamp>,
+
+    /// The time in UTC when the alert was fixed.
+    pub fixed_at: Option<Timestamp>,
+
+    /// The github api url of this alert.
+    pub url: Url,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The time in UTC when the alert was created.
+    pub created_at: Timestamp,
+}
+
+impl CodeScanningAlert<'_> {
+    /// Whether this alert still needs attention.
+    pub fn is_open(&self) -> bool { self.state == CodeScanningAlertState::Open }
+}
+
+/// The state of a code scanning alert.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum CodeScanningAlertState {
+    /// The alert is open.
+    Open,
+
+    /// The alert was dismissed by a user.
+    Dismissed,
+
+    /// The problem was fixed by a commit.
+    Fixed,
+
+    /// The alert was closed, ie. its branch was deleted.
+    Closed,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The rule that raised a code scanning alert.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CodeScanningRule<'a> {
+    /// The unique identifier of the rule used to detect the alert.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub id: Cow<'a, str>,
+
+    /// The name of the rule.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// The severity of the rule.
+    pub severity: Option<RuleSeverity>,
+
+    /// The security severity of the rule, only present for security rules.
+    pub security_severity_level: Option<AlertSeverity>,
+
+    /// A short description of the rule.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub description: Cow<'a, str>,
+
+    /// The tags of the rule, ie. `security` or `external/cwe/cwe-079`.
+    #[serde(default)]
+    pub tags: Vec<Cow<'a, str>>,
+}
+
+/// The severity of a code scanning rule.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RuleSeverity {
+    /// The rule has no severity.
+    None,
+
+    /// The rule is informational.
+    Note,
+
+    /// The rule is a warning.
+    Warning,
+
+    /// The rule is an error.
+    Error,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The tool that found the problem of a code scanning alert.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CodeScanningTool<'a> {
+    /// The name of the tool, ie. `CodeQL`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The version of the tool.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub version: Option<Cow<'a, str>>,
+
+    /// The GUID of the tool.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub guid: Option<Cow<'a, str>>,
+}
+
+/// A place a code scanning alert was found.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CodeScanningAlertInstance<'a> {
+    /// The git reference the alert was found on.
+    #[serde(rename = "ref")]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
+
+    /// The analysis that found the alert, ie. the workflow and job.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub analysis_key: Cow<'a, str>,
+
+    /// The environment the analysis ran in, this is a JSON encoded string.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub environment: Cow<'a, str>,
+
+    /// The state of the alert on this reference.
+    pub state: CodeScanningAlertState,
+
+    /// The SHA of the commit that was analyzed.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub commit_sha: Option<Cow<'a, str>>,
+
+    /// The message the tool reported.
+    #[serde(borrow)]
+    pub message: Option<CodeScanningMessage<'a>>,
+
+    /// Where in the code the problem is.
+    #[serde(borrow)]
+    pub location: Option<CodeScanningLocation<'a>>,
+}
+
+/// The message of a code scanning alert instance.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CodeScanningMessage<'a> {
+    /// The text of the message.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub text: Cow<'a, str>,
+}
+
+/// The place in a file a code scanning alert was found.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CodeScanningLocation<'a> {
+    /// The path of the file relative to the root of the repository.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub path: Cow<'a, str>,
+
+    /// The line the problem starts on.
+    pub start_line: UInt,
+
+    /// The line the problem ends on.
+    pub end_line: UInt,
+
+    /// The column the problem starts on.
+    pub start_column: UInt,
+
+    /// The column the problem ends on.
+    pub end_column: UInt,
+}
@@ -1,14 +1,17 @@
-use std::path::Path;
+use std::{borrow::Cow, path::Path};
 
-use github_derive::StringEnum;
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{datetime, AuthorAssociation, Dt, Installation, Org, Repository, User};
+use crate::{
+    api::{AuthorAssociation, Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::deserialize_cow_str,
+};
 
 /// The payload of a commit comment.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CommitCommentEvent<'a> {
     /// The action that was performed.
     pub action: CommitCommentAction,
@@ -24,7 +27,7 @@ pub struct CommitCommentEvent<'a> {
     /// Detailed information about the organization the app
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -42,10 +45,12 @@ pub struct CommitCommentEvent<'a> {
 #[non_exhaustive]
 pub enum CommitCommentAction {
     Created,
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// The payload of a check run event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CommitComment<'a> {
     /// The api url of the pull request.
     pub url: Url,
@@ -57,7 +62,8 @@ pub struct CommitComment<'a> {
     pub id: UInt,
 
     /// String identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The user who commented on this commit.
     #[serde(borrow)]
@@ -72,22 +78,22 @@ pub struct CommitComment<'a> {
     pub line: Option<UInt>,
 
     /// The relative file path.
-    pub path: Option<&'a Path>,
+    pub path: Option<Cow<'a, Path>>,
 
     /// The SHA of this commit.
-    pub commit_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub commit_id: Cow<'a, str>,
 
     /// Time in UTC this commit was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// Time in UTC this commit was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// The associated author of this commit.
     pub author_association: AuthorAssociation,
 
     /// The body of the message.
-    pub body: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub body: Cow<'a, str>,
 }
@@ -1,35 +1,148 @@
-use std::{borrow::Cow, collections::BTreeMap, fmt};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
 
-use chrono::{DateTime, NaiveDateTime, Utc};
-use serde::Deserialize;
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
 
+mod diff;
 mod enums;
 mod structs;
 
+pub use diff::{Diff, FieldDiff};
 pub use enums::{
-    AuthorAssociation, CheckStatus, ConclusionStatus, EventKind, FileStatus, IssueState,
-    LockReason, MergeStateStatus, RepoCreationType, RepoPermission, RepoSelection, Type,
-    WorkflowEvent,
+    AlertSeverity, AuthorAssociation, CheckStatus, ConclusionStatus, DeploymentState,
+    EventKind, FileStatus, IssueState, LockReason, MergeStateStatus, RepoCreationType,
+    RepoPermission, RepoSelection, Type, WorkflowEvent,
 };
 pub use structs::{
-    AccessPermissions, App, Base, Branch, Changes, Comment, Commit, CommitInner,
-    CommitTree, Committer, Head, IncomingComment, IncomingCommit, IncomingIssue,
-    IncomingPullRequest, IncomingRepository, IncomingTeam, IncomingUser,
-    IncomingWorkflow, IncomingWorkflowRun, Installation, Issue, Label, Links, Milestone,
-    Org, Permissions, Plan, PullRequest, Repository, ShortUser, SimpleCommit, Team, User,
-    Verification,
+    AccessPermissions, App, Base, Body, Branch, BranchRef, Changes, Comment,
+    CommentChanges, Commit, CommitInner, CommitTree, Committer, Deployment,
+    DeploymentStatus, Head, IncomingComment, IncomingCommit, IncomingDeployment,
+    IncomingDeploymentStatus, IncomingIssue, IncomingPullRequest, IncomingRepository,
+    IncomingTeam, IncomingUser, IncomingWorkflow, IncomingWorkflowRun, Installation,
+    Issue, Label, Links, Milestone, Org, Permissions, Plan, PullRequest, PullRequestRef,
+    RepoRef, Repository, ShortUser, SimpleCommit, SimpleOrg, SimpleRepository, Team,
+    User, Verification, Workflow, WorkflowRun,
 };
 
 pub type Dt = DateTime<Utc>;
 
+/// A time in a webhook payload.
+///
+/// Github sends most times as RFC 3339 strings but some, like the `created_at` of the
+/// repository in a push, as unix timestamps. The time is available as a [`Dt`] and
+/// serializes back to exactly what github sent.
+#[derive(Clone, Debug)]
+pub struct Timestamp {
+    time: Dt,
+    sent_as: SentAs,
+}
+
+#[derive(Clone, Debug)]
+enum SentAs {
+    Unix,
+    Text(Box<str>),
+}
+
+impl Timestamp {
+    /// The time in UTC.
+    pub fn time(&self) -> Dt { self.time }
+
+    /// Was the time sent as a unix timestamp.
+    pub fn is_unix(&self) -> bool { matches!(self.sent_as, SentAs::Unix) }
+}
+
+impl From<Dt> for Timestamp {
+    /// The time serializes as an RFC 3339 string in UTC.
+    fn from(time: Dt) -> Self {
+        let text = time.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        Self { time, sent_as: SentAs::Text(text.into()) }
+    }
+}
+
+impl From<Timestamp> for Dt {
+    fn from(timestamp: Timestamp) -> Self { timestamp.time }
+}
+
+impl Deref for Timestamp {
+    type Target = Dt;
+
+    fn deref(&self) -> &Self::Target { &self.time }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool { self.time == other.time }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering { self.time.cmp(&other.time) }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.time.hash(state) }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.time.fmt(f) }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(deser: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        match StringOrUInt::deserialize(deser)? {
+            StringOrUInt::UInt(timestamp) => Ok(Self {
+                time: from_timestamp(timestamp).map_err(D::Error::custom)?,
+                sent_as: SentAs::Unix,
+            }),
+            StringOrUInt::String(text) => Ok(Self {
+                time: text.parse().map_err(D::Error::custom)?,
+                sent_as: SentAs::Text(text.into()),
+            }),
+        }
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match &self.sent_as {
+            SentAs::Unix => ser.serialize_i64(self.time.timestamp()),
+            SentAs::Text(text) => ser.serialize_str(text),
+        }
+    }
+}
+
+/// Every `*_url` key of an object.
+///
+/// The values are kept as sent, including `null` and anything that is not a string,
+/// so the map serializes to the same keys and values it was parsed from.
 #[derive(Clone, Debug, Default)]
 pub struct UrlMap {
-    urls: BTreeMap<String, String>,
+    urls: BTreeMap<String, serde_json::Value>,
 }
 
 #[allow(unused)]
 impl UrlMap {
-    pub fn get(&self, k: &str) -> Option<&str> { self.urls.get(k).map(|s| s.as_str()) }
+    /// The url of `k`, `None` if it is missing or not a string.
+    pub fn get(&self, k: &str) -> Option<&str> { self.urls.get(k)?.as_str() }
     pub fn len(&self) -> usize { self.urls.len() }
     pub fn is_empty(&self) -> bool { self.urls.is_empty() }
 }
@@ -39,15 +152,14 @@ impl<'de> Deserialize<'de> for UrlMap {
     where
         D: serde::Deserializer<'de>,
     {
-        use serde::de::{MapAccess, Visitor};
+        use serde::de::{IgnoredAny, MapAccess, Visitor};
 
         struct UrlMapVisitor;
         impl<'a> Visitor<'a> for UrlMapVisitor {
             type Value = UrlMap;
 
-            // TODO: finish list
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("one of User, Owner, TODO")
+                formatter.write_str("an object with `*_url` keys")
             }
 
             fn visit_map<A: MapAccess<'a>>(
@@ -55,20 +167,11 @@ impl<'de> Deserialize<'de> for UrlMap {
                 mut map: A,
             ) -> Result<Self::Value, A::Error> {
                 let mut urls = BTreeMap::new();
-                // While there are entries remaining in the input, add them
-                // into our map.
-                while let Some((key, value)) = map
-                    .next_entry::<Option<String>, Option<String>>()
-                    .map_err(|_| {
-                        Ok::<_, A::Error>(Some((None::<String>, None::<String>)))
-                    })
-                    .ok()
-                    .flatten()
-                {
-                    if let (Some(key), Some(val)) = (key, value) {
-                        if key.ends_with("_url") {
-                            urls.insert(key, val);
-                        }
+                while let Some(key) = map.next_key::<String>()? {
+                    if key.ends_with("_url") {
+                        urls.insert(key, map.next_value()?);
+                    } else {
+                        map.next_value::<IgnoredAny>()?;
                     }
                 }
                 Ok(UrlMap { urls })
@@ -79,6 +182,15 @@ impl<'de> Deserialize<'de> for UrlMap {
     }
 }
 
+impl Serialize for UrlMap {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.urls.serialize(ser)
+    }
+}
+
 pub const fn true_fn() -> bool { true }
 
 pub fn default_null<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -90,6 +202,20 @@ where
     Ok(opt.unwrap_or_default())
 }
 
+/// Serializes the default value as `null`, this is the inverse of [`default_null`] for
+/// fields github sends as `null` instead of a value.
+pub fn null_default<T, S>(value: &T, ser: S) -> Result<S::Ok, S::Error>
+where
+    T: Default + PartialEq + Serialize,
+    S: serde::Serializer,
+{
+    if *value == T::default() {
+        ser.serialize_none()
+    } else {
+        value.serialize(ser)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum StringOrUInt<'a> {
@@ -97,6 +223,16 @@ enum StringOrUInt<'a> {
     String(Cow<'a, str>),
 }
 
+fn from_timestamp(timestamp: i64) -> Result<Dt, &'static str> {
+    NaiveDateTime::from_timestamp_opt(timestamp, 0)
+        .map(|time| Dt::from_utc(time, Utc))
+        .ok_or("timestamp exceeded bounds")
+}
+
+/// Deserializes a time sent as an RFC 3339 string or as a unix timestamp.
+///
+/// A [`Dt`] serializes as an RFC 3339 string in UTC, use a [`Timestamp`] to keep the
+/// format github sent.
 pub fn datetime<'de, D>(deser: D) -> Result<Dt, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -105,15 +241,16 @@ where
 
     let ts = StringOrUInt::deserialize(deser)?;
     match ts {
-        StringOrUInt::UInt(timestamp) => Ok(Dt::from_utc(
-            NaiveDateTime::from_timestamp_opt(timestamp, 0)
-                .ok_or_else(|| D::Error::custom("timestamp exceeded bounds"))?,
-            Utc,
-        )),
+        StringOrUInt::UInt(timestamp) => {
+            from_timestamp(timestamp).map_err(D::Error::custom)
+        }
         StringOrUInt::String(datetime) => datetime.parse().map_err(D::Error::custom),
     }
 }
 
+/// Like [`datetime`] but a missing or `null` time is `None`.
+///
+/// A string that is not a time is still an error, any other value is `None`.
 pub fn datetime_opt<'de, D>(deser: D) -> Result<Option<Dt>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -122,11 +259,9 @@ where
 
     let ts = StringOrUInt::deserialize(deser);
     Ok(Some(match ts {
-        Ok(StringOrUInt::UInt(timestamp)) => Dt::from_utc(
-            NaiveDateTime::from_timestamp_opt(timestamp, 0)
-                .ok_or_else(|| D::Error::custom("timestamp exceeded bounds"))?,
-            Utc,
-        ),
+        Ok(StringOrUInt::UInt(timestamp)) => {
+            from_timestamp(timestamp).map_err(D::Error::custom)?
+        }
         Ok(StringOrUInt::String(datetime)) => {
             datetime.parse().map_err(D::Error::custom)?
         }
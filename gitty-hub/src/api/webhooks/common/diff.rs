@@ -0,0 +1,108 @@
+use std::{borrow::Cow, fmt};
+
+use crate::api::Body;
+
+/// The longest a value is shown in a [`Diff`] before it is cut short.
+const MAX_LEN: usize = 40;
+
+/// What an `edited` action changed, built from the `changes` of an event and the
+/// edited object.
+///
+/// `Diff` displays as a short human readable summary, ie. `title: 'foo' → 'bar'`.
+#[derive(Clone, Debug, Default)]
+pub struct Diff<'a> {
+    fields: Vec<FieldDiff<'a>>,
+}
+
+/// A single field that was edited.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff<'a> {
+    /// The name of the field, ie. `title`.
+    pub field: &'static str,
+
+    /// The value before the edit.
+    pub from: Cow<'a, str>,
+
+    /// The value after the edit.
+    pub to: Cow<'a, str>,
+}
+
+impl<'a> Diff<'a> {
+    /// Adds `field` if there is a previous value and it is different from `to`.
+    pub fn field(
+        mut self,
+        field: &'static str,
+        from: Option<&'a Body<'_>>,
+        to: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        if let Some(from) = from {
+            let to = to.into();
+            if from.from != to {
+                self.fields.push(FieldDiff {
+                    field,
+                    from: Cow::Borrowed(&*from.from),
+                    to,
+                });
+            }
+        }
+        self
+    }
+
+    /// Returns `true` if nothing that is modelled was changed.
+    pub fn is_empty(&self) -> bool { self.fields.is_empty() }
+
+    /// The fields that were changed.
+    pub fn fields(&self) -> &[FieldDiff<'a>] { &self.fields }
+}
+
+impl fmt::Display for Diff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, field) in self.fields.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ")?;
+            }
+            field.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for FieldDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: '{}' → '{}'", self.field, Short(&self.from), Short(&self.to))
+    }
+}
+
+/// Shows the first line of a value up to `MAX_LEN` characters.
+struct Short<'a>(&'a str);
+
+impl fmt::Display for Short<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = self.0.trim().lines().next().unwrap_or_default();
+        match line.char_indices().nth(MAX_LEN) {
+            Some((end, _)) => write!(f, "{}…", &line[..end]),
+            None if line.len() < self.0.trim().len() => write!(f, "{}…", line),
+            None => f.write_str(line),
+        }
+    }
+}
+
+#[test]
+fn diff_display() {
+    let from = Body { from: "foo".into() };
+    let long = Body { from: "a long first line\nand more".into() };
+
+    let diff = Diff::default()
+        .field("title", Some(&from), "bar")
+        .field("body", Some(&long), "x".repeat(50))
+        .field("color", Some(&from), "foo")
+        .field("name", None, "baz");
+    assert_eq!(
+        diff.to_string(),
+        format!(
+            "title: 'foo' → 'bar', body: 'a long first line…' → '{}…'",
+            "x".repeat(40)
+        )
+    );
+    assert!(Diff::default().is_empty());
+}
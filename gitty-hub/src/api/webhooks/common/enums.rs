@@ -1,7 +1,5 @@
-use std::fmt;
-
 use github_derive::StringEnum;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -15,36 +13,28 @@ impl<'de> Deserialize<'de> for Type {
     where
         D: serde::Deserializer<'de>,
     {
-        use serde::de::{Error, Unexpected, Visitor};
-
-        struct StrVisitor;
-
-        impl<'a> Visitor<'a> for StrVisitor {
-            type Value = &'a str;
-
-            // TODO: finish list
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("one of User, Organization")
-            }
-
-            fn visit_borrowed_str<E: Error>(self, v: &'a str) -> Result<Self::Value, E> {
-                Ok(v) // so easy
-            }
+        use serde::de::{Error, Unexpected};
 
-            fn visit_borrowed_bytes<E: Error>(
-                self,
-                v: &'a [u8],
-            ) -> Result<Self::Value, E> {
-                std::str::from_utf8(v)
-                    .map_err(|_| Error::invalid_value(Unexpected::Bytes(v), &self))
-            }
-        }
-        let s = d.deserialize_str(StrVisitor)?;
-
-        match s {
+        let s = crate::utils::deserialize_cow_str(d)?;
+        match &*s {
             "User" => Ok(Self::User),
             "Organization" => Ok(Self::Organization),
-            _ => Err(Error::invalid_value(Unexpected::Str(s), &StrVisitor)),
+            _ => Err(Error::invalid_value(
+                Unexpected::Str(&s),
+                &"one of User, Organization",
+            )),
+        }
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::User => "User".serialize(ser),
+            Self::Organization => "Organization".serialize(ser),
         }
     }
 }
@@ -70,38 +60,32 @@ impl<'de> Deserialize<'de> for LockReason {
     where
         D: serde::Deserializer<'de>,
     {
-        use serde::de::{Error, Unexpected, Visitor};
-
-        struct StrVisitor;
-
-        impl<'a> Visitor<'a> for StrVisitor {
-            type Value = &'a str;
-
-            // TODO: finish list
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("one of User, Organization")
-            }
-
-            fn visit_borrowed_str<E: Error>(self, v: &'a str) -> Result<Self::Value, E> {
-                Ok(v) // so easy
-            }
+        use serde::de::{Error, Unexpected};
 
-            fn visit_borrowed_bytes<E: Error>(
-                self,
-                v: &'a [u8],
-            ) -> Result<Self::Value, E> {
-                std::str::from_utf8(v)
-                    .map_err(|_| Error::invalid_value(Unexpected::Bytes(v), &self))
-            }
-        }
-        let s = d.deserialize_str(StrVisitor)?;
-
-        match s {
+        let s = crate::utils::deserialize_cow_str(d)?;
+        match &*s {
             "resolved" => Ok(Self::Resolved),
             "off-topic" => Ok(Self::OffTopic),
             "too heated" => Ok(Self::TooHeated),
             "spam" => Ok(Self::Spam),
-            _ => Err(Error::invalid_value(Unexpected::Str(s), &StrVisitor)),
+            _ => Err(Error::invalid_value(
+                Unexpected::Str(&s),
+                &"one of resolved, off-topic, too heated, spam",
+            )),
+        }
+    }
+}
+
+impl Serialize for LockReason {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Resolved => "resolved".serialize(ser),
+            Self::OffTopic => "off-topic".serialize(ser),
+            Self::TooHeated => "too heated".serialize(ser),
+            Self::Spam => "spam".serialize(ser),
         }
     }
 }
@@ -112,6 +96,8 @@ impl<'de> Deserialize<'de> for LockReason {
 pub enum RepoSelection {
     All,
     Selected,
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 #[derive(Clone, Debug, StringEnum)]
@@ -121,9 +107,16 @@ pub enum RepoPermission {
     /// Read only access.
     Read,
 
+    /// Read access and the ability to manage issues and pull requests.
+    Triage,
+
     /// Write access.
     Write,
 
+    /// Write access and the ability to manage the repository without access to
+    /// sensitive or destructive actions.
+    Maintain,
+
     /// Complete administrative access.
     Admin,
 
@@ -131,12 +124,38 @@ pub enum RepoPermission {
     ///
     /// This is the equivalent of `null` or not being present in the JSON.
     None,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for RepoPermission {
     fn default() -> Self { Self::Read }
 }
 
+impl RepoPermission {
+    /// Returns `true` if this permission grants at least the access of `other`.
+    ///
+    /// From least to most access the levels are `none`, `read`, `triage`, `write`,
+    /// `maintain` and `admin`.
+    ///
+    /// A permission github added later has no known level and is treated as `none`, so
+    /// this returns `false` for it against anything above `none` even if the new role
+    /// grants more. Compare [`as_ref`](AsRef::as_ref) with the role's name to allow it.
+    pub fn is_at_least(&self, other: &Self) -> bool { self.level() >= other.level() }
+
+    fn level(&self) -> u8 {
+        match self {
+            Self::None | Self::_Custom(_) => 0,
+            Self::Read => 1,
+            Self::Triage => 2,
+            Self::Write => 3,
+            Self::Maintain => 4,
+            Self::Admin => 5,
+        }
+    }
+}
+
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "lowercase")]
 #[non_exhaustive]
@@ -144,6 +163,8 @@ pub enum RepoCreationType {
     All,
     Private,
     None,
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for RepoCreationType {
@@ -157,6 +178,8 @@ pub enum IssueState {
     Open,
     Closed,
     Unknown,
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for IssueState {
@@ -176,6 +199,7 @@ pub enum EventKind {
     ContentReference,
     Create,
     Delete,
+    DependabotAlert,
     Deployment,
     DeploymentReview,
     DeploymentStatus,
@@ -207,6 +231,7 @@ pub enum EventKind {
     Release,
     Repository,
     RepositoryDispatch,
+    RepositoryVulnerabilityAlert,
     SecretScanningAlert,
     Star,
     Status,
@@ -214,7 +239,10 @@ pub enum EventKind {
     TeamAdd,
     Watch,
     WorkflowDispatch,
+    WorkflowJob,
     WorkflowRun,
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 #[derive(Clone, Debug, StringEnum)]
@@ -229,13 +257,15 @@ pub enum AuthorAssociation {
     Member,
     Owner,
     None,
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for AuthorAssociation {
     fn default() -> Self { Self::None }
 }
 
-#[derive(Debug, Copy, Clone, StringEnum)]
+#[derive(Debug, Clone, StringEnum)]
 #[github_enum(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum CheckStatus {
@@ -251,15 +281,24 @@ pub enum CheckStatus {
     /// The check has been requested.
     Requested,
 
+    /// The check is waiting for a deployment protection rule to pass.
+    Waiting,
+
+    /// The check is pending until a concurrency group frees up.
+    Pending,
+
     /// `None` is that same as not present or null.
     None,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for CheckStatus {
     fn default() -> Self { Self::None }
 }
 
-#[derive(Debug, Copy, Clone, StringEnum)]
+#[derive(Debug, Clone, PartialEq, Eq, StringEnum)]
 #[github_enum(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ConclusionStatus {
@@ -276,8 +315,12 @@ pub enum ConclusionStatus {
     Cancelled,
 
     /// The check has timed out.
+    #[github_enum(rename = "timed_out")]
     TimeOut,
 
+    /// The check was skipped.
+    Skipped,
+
     /// The check needs attention.
     ActionRequired,
 
@@ -288,12 +331,45 @@ pub enum ConclusionStatus {
 
     /// `None` is that same as not present or null.
     None,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 impl Default for ConclusionStatus {
     fn default() -> Self { Self::None }
 }
 
+/// The state of a deployment status.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentState {
+    /// The deployment is waiting to start.
+    Pending,
+
+    /// The deployment is queued.
+    Queued,
+
+    /// The deployment is in progress.
+    InProgress,
+
+    /// The deployment finished successfully.
+    Success,
+
+    /// The deployment failed.
+    Failure,
+
+    /// The deployment could not be run.
+    Error,
+
+    /// The deployment was replaced by a later deployment to the same environment.
+    Inactive,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
 /// The event that triggered a workflow run.
 #[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
 #[github_enum(rename_all = "snake_case")]
@@ -307,6 +383,9 @@ pub enum WorkflowEvent {
 
     /// An issue opened that has a workflow associated with it.
     Issue,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// The status of a file tracked by github.
@@ -328,6 +407,9 @@ pub enum FileStatus {
 
     // TODO confirm
     Changed,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// The status of a file tracked by github.
@@ -358,4 +440,31 @@ pub enum MergeStateStatus {
 
     /// Mergeable with non-passing commit status.
     Unstable,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The severity of a security alert or advisory.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AlertSeverity {
+    /// A low severity vulnerability.
+    Low,
+
+    /// A medium severity vulnerability.
+    Medium,
+
+    /// A medium severity vulnerability, older payloads send `moderate`.
+    Moderate,
+
+    /// A high severity vulnerability.
+    High,
+
+    /// A critical severity vulnerability.
+    Critical,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
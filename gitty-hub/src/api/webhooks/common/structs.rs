@@ -1,19 +1,22 @@
 use std::{borrow::Cow, fmt, path::Path};
 
-use github_derive::Incoming;
+use github_derive::{Incoming, IntoOwned};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{
-    datetime, datetime_opt, default_null, AuthorAssociation, CheckStatus,
-    ConclusionStatus, Dt, EventKind, IssueState, LockReason, MergeStateStatus,
-    RepoCreationType, RepoPermission, RepoSelection, Type, UrlMap,
+use crate::{
+    api::{
+        default_null, AuthorAssociation, CheckStatus, ConclusionStatus, DeploymentState,
+        EventKind, IssueState, LockReason, MergeStateStatus, RepoCreationType,
+        RepoPermission, RepoSelection, Timestamp, Type, UrlMap,
+    },
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
 };
 
 /// Permissions given to the installed app for accessing metadata, contents, and
 /// issues.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming)]
 pub struct AccessPermissions {
     /// Permission for accessing actions.
     #[serde(default, deserialize_with = "default_null")]
@@ -145,19 +148,22 @@ pub struct AccessPermissions {
 }
 
 /// Information about the installed app.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct App<'a> {
     /// Numeric Id of this team.
     pub id: UInt,
 
     /// String identifier of the team.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this team.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The slug of this team.
-    pub slug: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub slug: Option<Cow<'a, str>>,
 
     /// The owner of this app.
     #[serde(borrow)]
@@ -167,38 +173,43 @@ pub struct App<'a> {
     pub html_url: Url,
 
     /// The external url related to this app.
-    pub external_url: Url,
+    ///
+    /// This is whatever the owner entered so it is kept as sent rather than parsed.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub external_url: Cow<'a, str>,
 
     /// Description of the repo.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// Permissions required for this team.
     pub permissions: AccessPermissions,
 
     /// The time in UTC when the team was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// The time in UTC when the team was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// Events that this app has access to.
     pub events: Vec<EventKind>,
 }
 
 /// The base branch of a commit.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Base<'a> {
     /// A name for this base `username:branch`.
-    pub label: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub label: Cow<'a, str>,
 
     /// The name of the branch.
     #[serde(rename = "ref")]
-    pub ref_: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
 
     /// The SHA of this commit on a branch.
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 
     /// The user who's base branch this is from.
     #[serde(borrow)]
@@ -208,17 +219,19 @@ pub struct Base<'a> {
     pub repo: Option<Repository<'a>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Body<'a> {
     /// The previous version of the body.
-    pub from: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub from: Cow<'a, str>,
 }
 
 /// Information about a branch.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Branch<'a> {
     /// The name of this branch.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The last commit to this branch.
     #[serde(borrow)]
@@ -228,47 +241,101 @@ pub struct Branch<'a> {
     pub protected: bool,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Changes<'a> {
     /// The changes made to the body.
     ///
-    /// The is present for issues, pulls, and comments.
+    /// This is present for projects.
     #[serde(borrow)]
     pub body: Option<Body<'a>>,
 
-    /// The changes made to the title.
-    #[serde(borrow)]
-    pub title: Option<Body<'a>>,
-
     /// The changes made to the name.
     ///
-    /// This is present for releases, there may be other uses of it also, Github's
-    /// API docs are so-so.
+    /// This is present for projects, project columns and teams.
     #[serde(borrow)]
     pub name: Option<Body<'a>>,
 
-    /// The changes made to the due_on attribute.
-    ///
-    /// This is present for milestones, there may be other uses of it also, Github's
-    /// API docs are so-so.
-    #[serde(borrow)]
-    pub due_on: Option<Body<'a>>,
-
     /// The changes made to the description.
     ///
-    /// This is present for milestones, there may be other uses of it also, Github's
-    /// API docs are so-so.
+    /// This is present for teams, there may be other uses of it also, Github's API
+    /// docs are so-so.
     #[serde(borrow)]
     pub description: Option<Body<'a>>,
+
+    /// The changes made to a collaborator's permission.
+    #[serde(borrow, alias = "old_permission")]
+    pub permission: Option<PermissionChange<'a>>,
+
+    /// The changes made to the privacy of a team.
+    #[serde(borrow)]
+    pub privacy: Option<Body<'a>>,
+
+    /// The changes made to the default branch of a repository.
+    #[serde(borrow)]
+    pub default_branch: Option<Body<'a>>,
+
+    /// The changes made to the homepage of a repository.
+    #[serde(borrow)]
+    pub homepage: Option<Body<'a>>,
+
+    /// The changes made to the login of an organization.
+    #[serde(borrow)]
+    pub login: Option<Body<'a>>,
+
+    /// The changes made to a repository, this is present when a repository is renamed
+    /// or a team's access to a repository changes.
+    #[serde(borrow)]
+    pub repository: Option<RepositoryChanges<'a>>,
+}
+
+/// The changes made to a comment or review.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct CommentChanges<'a> {
+    /// The previous body.
+    #[serde(borrow)]
+    pub body: Option<Body<'a>>,
+}
+
+/// The change of a collaborator's permission.
+///
+/// When a collaborator is added only `to` is present.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct PermissionChange<'a> {
+    /// The previous permission.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub from: Option<Cow<'a, str>>,
+
+    /// The new permission.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub to: Option<Cow<'a, str>>,
+}
+
+/// The changes made to a repository.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct RepositoryChanges<'a> {
+    /// The previous name of the repository.
+    #[serde(borrow)]
+    pub name: Option<Body<'a>>,
+
+    /// The previous permissions a team had on the repository.
+    pub permissions: Option<PermissionsChanges>,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+/// The changes made to a team's permissions on a repository.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PermissionsChanges {
+    /// The previous permissions.
+    pub from: Permissions,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Comment<'a> {
     /// Numeric identifier of the comment.
     pub id: UInt,
 
     /// String identifier of the comment.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The Github api url.
     pub url: Url,
@@ -277,7 +344,8 @@ pub struct Comment<'a> {
     pub html_url: Url,
 
     /// The body of the comment.
-    pub body: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub body: Option<Cow<'a, str>>,
 
     /// The user who wrote the comment.
     #[serde(borrow)]
@@ -296,20 +364,19 @@ pub struct Comment<'a> {
     /// The relative path of the file.
     ///
     /// This only applies to pull requests and commits.
-    pub path: Option<&'a Path>,
+    pub path: Option<Cow<'a, Path>>,
 
     /// The SHA of the commit the comment applies to.
     ///
     /// This only applies to pull requests and commits.
-    pub commit_id: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub commit_id: Option<Cow<'a, str>>,
 
     /// Time in UTC this comment was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// Time in UTC this comment was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// The association of the author to the repository.
     pub author_association: AuthorAssociation,
@@ -320,26 +387,30 @@ pub struct Comment<'a> {
 }
 
 /// Information about a specific commit.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Commit<'a> {
     /// The sha of this commit.
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 
     /// The identifier of this commit.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// Information about this commit.
     #[serde(borrow)]
     pub commit: CommitInner<'a>,
 
     /// The api url of the commit referenced.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// The url to github webpage associated with this commit.
     pub html_url: Url,
 
     /// The api url to request information about comments.
-    pub comments_url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub comments_url: Cow<'a, str>,
 
     /// The author of this commit.
     #[serde(borrow)]
@@ -355,19 +426,19 @@ pub struct Commit<'a> {
 
     /// The files that were added.
     #[serde(default)]
-    pub added: Vec<&'a str>,
+    pub added: Vec<Cow<'a, str>>,
 
     /// The files that were removed.
     #[serde(default)]
-    pub removed: Vec<&'a str>,
+    pub removed: Vec<Cow<'a, str>>,
 
     /// The files that were modified.
     #[serde(default)]
-    pub modified: Vec<&'a str>,
+    pub modified: Vec<Cow<'a, str>>,
 }
 
 /// Further information about a commit.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct CommitInner<'a> {
     /// The url to this commit.
     pub url: Url,
@@ -381,7 +452,8 @@ pub struct CommitInner<'a> {
     pub committer: ShortUser<'a>,
 
     /// The commit message.
-    pub message: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub message: Cow<'a, str>,
 
     /// SHA and url of the commit.
     #[serde(borrow)]
@@ -396,43 +468,179 @@ pub struct CommitInner<'a> {
 }
 
 /// Information about the author/committer.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Committer<'a> {
     /// The git author's name.
-    pub name: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub name: Option<Cow<'a, str>>,
 
     /// The git author's email.
-    pub email: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub email: Option<Cow<'a, str>>,
 
     /// The UTC date of the latest commit.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub date: Option<Dt>,
+    pub date: Option<Timestamp>,
 
     /// The author's github username.
-    pub username: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub username: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct CommitTree<'a> {
     /// SHA of the commit.
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 
     /// The url of this commit.
     pub url: Url,
 }
 
+/// Information about a deployment.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct Deployment<'a> {
+    /// The github api url of this deployment.
+    pub url: Url,
+
+    /// Numeric Id of this deployment.
+    pub id: UInt,
+
+    /// String identifier of the deployment.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The SHA of the commit that is being deployed.
+    ///
+    /// This is not present when the deployment is part of a check run.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub sha: Option<Cow<'a, str>>,
+
+    /// The ref that is being deployed, this can be a branch, tag or SHA.
+    ///
+    /// This is not present when the deployment is part of a check run.
+    #[serde(rename = "ref")]
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub ref_: Option<Cow<'a, str>>,
+
+    /// The task being run ie. `deploy` or `deploy:migrations`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub task: Cow<'a, str>,
+
+    /// Extra information that was passed along when the deployment was created.
+    #[serde(default)]
+    pub payload: serde_json::Value,
+
+    /// The current environment for this deployment.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub environment: Cow<'a, str>,
+
+    /// The environment this deployment was originally created for.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub original_environment: Cow<'a, str>,
+
+    /// A short description of the deployment.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// The user who created the deployment.
+    #[serde(borrow)]
+    pub creator: Option<User<'a>>,
+
+    /// The time in UTC when the deployment was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the deployment was updated.
+    pub updated_at: Timestamp,
+
+    /// The github api url of the statuses of this deployment.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub statuses_url: Option<Cow<'a, str>>,
+
+    /// The github api url of the repository being deployed.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub repository_url: Option<Cow<'a, str>>,
+
+    /// Will this environment be removed once it is no longer in use.
+    #[serde(default)]
+    pub transient_environment: bool,
+
+    /// Is this deployment to an environment users interact with.
+    #[serde(default)]
+    pub production_environment: bool,
+}
+
+/// The status of a deployment.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct DeploymentStatus<'a> {
+    /// The github api url of this status.
+    pub url: Url,
+
+    /// Numeric Id of this status.
+    pub id: UInt,
+
+    /// String identifier of this status.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The state of the deployment.
+    pub state: DeploymentState,
+
+    /// The user who created the status.
+    #[serde(borrow)]
+    pub creator: Option<User<'a>>,
+
+    /// A short description of the status.
+    #[serde(default)]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub description: Cow<'a, str>,
+
+    /// The environment of the deployment.
+    #[serde(default)]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub environment: Cow<'a, str>,
+
+    /// The url of the deployment's output, this is the same as `log_url`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub target_url: Option<Cow<'a, str>>,
+
+    /// The url of the deployment's output.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub log_url: Option<Cow<'a, str>>,
+
+    /// The url of the deployed environment.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub environment_url: Option<Cow<'a, str>>,
+
+    /// The time in UTC when the status was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the status was updated.
+    pub updated_at: Timestamp,
+
+    /// The github api url of the deployment.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub deployment_url: Option<Cow<'a, str>>,
+
+    /// The github api url of the repository being deployed.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub repository_url: Option<Cow<'a, str>>,
+}
+
 /// Information about a head branch.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Head<'a> {
     /// A name for this base `username:branch`.
-    pub label: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub label: Option<Cow<'a, str>>,
 
     /// The name of the branch.
     #[serde(rename = "ref")]
-    pub ref_: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
 
     /// The SHA of this commit on a branch.
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 
     /// The user who's base branch this is from.
     pub user: Option<User<'a>>,
@@ -441,7 +649,7 @@ pub struct Head<'a> {
     pub repo: Option<Repository<'a>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Installation<'a> {
     /// Numeric Id of this installation.
     pub id: UInt,
@@ -472,15 +680,14 @@ pub struct Installation<'a> {
     pub events: Vec<EventKind>,
 
     /// Time in UTC this app was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// Time in UTC this app was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// The configuration file for this installed app.
-    pub single_file_name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub single_file_name: Cow<'a, str>,
 
     /// A map of all the github api urls.
     #[serde(flatten, default)]
@@ -490,16 +697,18 @@ pub struct Installation<'a> {
 /// Information about an issue.
 ///
 /// This can be used to represent pull request related responses.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Issue<'a> {
     /// Numeric Id of this repository.
     pub id: UInt,
 
     /// String identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The api url of the issue.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// The public web page url.
     pub html_url: Url,
@@ -515,14 +724,16 @@ pub struct Issue<'a> {
     pub locked: bool,
 
     /// The title of this issue.
-    pub title: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub title: Cow<'a, str>,
 
     /// Information about the user.
     #[serde(borrow)]
     pub user: User<'a>,
 
     /// The body of the issue.
-    pub body: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub body: Cow<'a, str>,
 
     /// A list of labels attached to this issue.
     #[serde(default, borrow)]
@@ -549,16 +760,13 @@ pub struct Issue<'a> {
     pub pull_request: Option<IssuePullRequest<'a>>,
 
     /// Time in UTC this pull request was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// Time in UTC this pull request was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// Time in UTC this pull request was closed.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub closed_at: Option<Dt>,
+    pub closed_at: Option<Timestamp>,
 
     /// The author associated with this issue.
     pub author_association: AuthorAssociation,
@@ -568,10 +776,11 @@ pub struct Issue<'a> {
 }
 
 /// Information about an pull requests linked to this issue.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct IssuePullRequest<'a> {
     /// The api url of the pull request.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// The public web page url.
     pub html_url: Url,
@@ -584,71 +793,82 @@ pub struct IssuePullRequest<'a> {
 }
 
 /// Information any labels.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Label<'a> {
     /// Numeric Id of this label.
     pub id: UInt,
 
     /// String identifier of the label.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this label.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The short description of this label.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// Background color of the label box.
-    pub color: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub color: Cow<'a, str>,
 
     /// Is this a default label.
     pub default: bool,
 }
 
 /// Information abou the license of a repository.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct License<'a> {
     /// The license key, this is usually an abbreviation of the license type.
-    pub key: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub key: Cow<'a, str>,
 
     /// The full name of the license.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// Capitalized key.
-    pub spdx_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub spdx_id: Cow<'a, str>,
 
     /// Github api url to information about the license.
-    pub url: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub url: Option<Cow<'a, str>>,
 
     /// A string identifier of this asset.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 }
 
 /// The links related to an issue or pull request.
-#[derive(Clone, Debug, Incoming)]
+#[derive(Clone, Debug, Incoming, IntoOwned)]
 #[no_deserialize]
 pub struct Links<'a> {
-    pub self_link: Option<&'a str>,
-    pub html_link: Option<&'a str>,
-    pub issue_link: Option<&'a str>,
-    pub comments_link: Option<&'a str>,
-    pub review_comments_link: Option<&'a str>,
-    pub review_comment_link: Option<&'a str>,
-    pub commits_link: Option<&'a str>,
-    pub statuses_link: Option<&'a str>,
-    pub pull_request_link: Option<&'a str>,
+    pub self_link: Option<Cow<'a, str>>,
+    pub html_link: Option<Cow<'a, str>>,
+    pub issue_link: Option<Cow<'a, str>>,
+    pub comments_link: Option<Cow<'a, str>>,
+    pub review_comments_link: Option<Cow<'a, str>>,
+    pub review_comment_link: Option<Cow<'a, str>>,
+    pub commits_link: Option<Cow<'a, str>>,
+    pub statuses_link: Option<Cow<'a, str>>,
+    pub pull_request_link: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Milestone<'a> {
     /// Numeric Id of this milestone.
     pub id: UInt,
 
     /// String identifier of the milestone.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this milestone.
-    pub name: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub name: Option<Cow<'a, str>>,
 
     /// Information about the creator of this milestone.
     #[serde(borrow)]
@@ -658,13 +878,16 @@ pub struct Milestone<'a> {
     pub html_url: Url,
 
     /// The url to the github api of this repo.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// The url to the github api labels requests.
-    pub labels_url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub labels_url: Cow<'a, str>,
 
     /// Description of the repo.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// The number this milestone is.
     pub number: UInt,
@@ -674,7 +897,8 @@ pub struct Milestone<'a> {
     pub state: IssueState,
 
     /// The title of this milestone.
-    pub title: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub title: Cow<'a, str>,
 
     /// The number of open issues related to this milestone.
     #[serde(default)]
@@ -685,34 +909,32 @@ pub struct Milestone<'a> {
     pub closed_issues: UInt,
 
     /// The time in UTC when the milestone was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// The time in UTC when the milestone was last updated.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub updated_at: Option<Dt>,
+    pub updated_at: Option<Timestamp>,
 
     /// The time in UTC when the milestone was closed.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub closed_at: Option<Dt>,
+    pub closed_at: Option<Timestamp>,
 
     /// The time in UTC when the milestone is due.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub due_on: Option<Dt>,
+    pub due_on: Option<Timestamp>,
 }
 
 /// Information about a github organization.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Org<'a> {
     /// The name of the organization.
-    pub login: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub login: Cow<'a, str>,
 
     /// Numeric identifier of the organization.
     #[serde(default)]
     pub id: UInt,
 
     /// String identifier of the organization.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The url to the organizations github api.
     pub url: Url,
@@ -722,25 +944,32 @@ pub struct Org<'a> {
 
     /// A description of the organization.
     #[serde(default)]
-    pub description: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub description: Cow<'a, str>,
 
     /// The name of the organization.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The name of the company associated with this organization.
-    pub company: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub company: Option<Cow<'a, str>>,
 
     /// Url to a blog associated with this organization.
-    pub blog: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub blog: Option<Cow<'a, str>>,
 
     /// The location of this organization.
-    pub location: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub location: Option<Cow<'a, str>>,
 
     /// An email address for this organization.
-    pub email: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub email: Option<Cow<'a, str>>,
 
     /// The twitter user associated with this organization.
-    pub twitter_username: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub twitter_username: Option<Cow<'a, str>>,
 
     /// Is this organization verified.
     pub is_verified: bool,
@@ -772,12 +1001,10 @@ pub struct Org<'a> {
     pub html_url: Url,
 
     /// Time in UTC this organization was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// Time in UTC this organization was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// Type of resource this is.
     #[serde(rename = "type")]
@@ -807,7 +1034,8 @@ pub struct Org<'a> {
     pub collaborators: UInt,
 
     /// The email of the person who pays.
-    pub billing_email: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub billing_email: Option<Cow<'a, str>>,
 
     /// The plan this organization is using.
     pub plan: Option<Plan<'a>>,
@@ -849,7 +1077,7 @@ pub struct Org<'a> {
 }
 
 /// The parent of some child commit.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Parent<'a> {
     /// The github api url to this commit.
     pub url: Url,
@@ -858,30 +1086,40 @@ pub struct Parent<'a> {
     pub html_url: Option<Url>,
 
     /// The SHA to this commit.
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 }
 
 /// The permissions a repository has.
-#[derive(Clone, Debug, Default, Deserialize, Incoming)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Incoming)]
 pub struct Permissions {
     /// Administrative privileges.
     #[serde(default)]
     pub admin: bool,
 
+    /// Can settings be managed, without access to destructive actions.
+    #[serde(default)]
+    pub maintain: bool,
+
     /// Are pushes enabled.
     #[serde(default)]
     pub push: bool,
 
+    /// Can issues and pull requests be managed, without write access.
+    #[serde(default)]
+    pub triage: bool,
+
     /// Is pulling permitted.
     #[serde(default)]
     pub pull: bool,
 }
 
 /// Information about a user/organizations plan.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Plan<'a> {
     /// The name of this plan.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// How much space does the organization have.
     #[serde(default)]
@@ -900,16 +1138,18 @@ pub struct Plan<'a> {
     pub seats: UInt,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct PullRequest<'a> {
     /// The api url of the pull request.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// Numeric Id of this repository.
     pub id: UInt,
 
     /// String identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The public web page url.
     pub html_url: Url,
@@ -932,28 +1172,28 @@ pub struct PullRequest<'a> {
     pub locked: bool,
 
     /// The title of this pull request.
-    pub title: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub title: Cow<'a, str>,
 
     /// Information about the user.
     #[serde(borrow)]
     pub user: User<'a>,
 
     /// The body of the pull request message.
-    pub body: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub body: Cow<'a, str>,
 
     /// Time in UTC this pull request was created.
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// Time in UTC this pull request was last updated.
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// Time in UTC this pull request was closed.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub closed_at: Option<Dt>,
+    pub closed_at: Option<Timestamp>,
 
     /// Time in UTC this pull request was last updated.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub merged_at: Option<Dt>,
+    pub merged_at: Option<Timestamp>,
 
     /// The user who merged this pull request.
     pub merged_by: Option<User<'a>>,
@@ -963,7 +1203,8 @@ pub struct PullRequest<'a> {
     /// If the pull request was merged this is the merge commit, if squashed this is
     /// the squash commit, and if rebased this is the commit that the base
     /// branch was updated to.
-    pub merge_commit_sha: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub merge_commit_sha: Option<Cow<'a, str>>,
 
     /// The association of the user who opened the pull request.
     pub author_association: AuthorAssociation,
@@ -1059,19 +1300,22 @@ pub struct PullRequest<'a> {
 }
 
 /// Information about a repository.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Repository<'a> {
     /// Numeric Id of this repository.
     pub id: UInt,
 
     /// String identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this repository.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The name including owner ie. `owner/repo-name`.
-    pub full_name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub full_name: Cow<'a, str>,
 
     /// The visibility of this repo.
     #[serde(default)]
@@ -1089,28 +1333,29 @@ pub struct Repository<'a> {
     pub html_url: Url,
 
     /// The url to the github api of this repo.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// Description of the repo.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// The time in UTC when the repo was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// The time in UTC when the repo was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// The time in UTC when the repo was last pushed to.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub pushed_at: Option<Dt>,
+    pub pushed_at: Option<Timestamp>,
 
     /// The url used when doing git operations.
-    pub git_url: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub git_url: Option<Cow<'a, str>>,
 
     /// The url used when doing ssh operations.
-    pub ssh_url: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub ssh_url: Option<Cow<'a, str>>,
 
     /// The url used to clone this repo.
     pub clone_url: Option<Url>,
@@ -1119,7 +1364,8 @@ pub struct Repository<'a> {
     pub svn_url: Option<Url>,
 
     /// The homepage of this repo, if set.
-    pub homepage: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub homepage: Option<Cow<'a, str>>,
 
     /// Size of the repository.
     #[serde(default)]
@@ -1138,7 +1384,8 @@ pub struct Repository<'a> {
     pub subscribers_count: UInt,
 
     /// The programming language used for this repo.
-    pub language: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub language: Option<Cow<'a, str>>,
 
     /// Does this repo allow issues.
     #[serde(default)]
@@ -1165,7 +1412,8 @@ pub struct Repository<'a> {
     pub forks_count: UInt,
 
     /// The url to the repository this repo mirrors.
-    pub mirror_url: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub mirror_url: Option<Cow<'a, str>>,
 
     /// Has this repo been archived.
     #[serde(default)]
@@ -1183,7 +1431,8 @@ pub struct Repository<'a> {
     pub license: Option<License<'a>>,
 
     /// Temporary clone token.
-    pub temp_clone_token: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub temp_clone_token: Option<Cow<'a, str>>,
 
     /// Number of forks for the repo.
     #[serde(default)]
@@ -1202,7 +1451,8 @@ pub struct Repository<'a> {
     pub stargazers: UInt,
 
     /// This repositories default branch.
-    pub default_branch: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub default_branch: Option<Cow<'a, str>>,
 
     /// Allow squash and merge in web merge.
     #[serde(default = "crate::api::true_fn")]
@@ -1222,7 +1472,7 @@ pub struct Repository<'a> {
 
     /// The topics this repo covers.
     #[serde(default)]
-    pub topics: Vec<&'a str>,
+    pub topics: Vec<Cow<'a, str>>,
 
     /// The set permissions of this repo.
     #[serde(default)]
@@ -1233,19 +1483,22 @@ pub struct Repository<'a> {
     pub all_urls: UrlMap,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct SimpleCommit<'a> {
     /// SHA of the head commit.
-    pub id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub id: Cow<'a, str>,
 
     /// SHA of the tree this commit is a part of.
-    pub tree_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub tree_id: Cow<'a, str>,
 
     /// Commit message.
-    pub message: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub message: Cow<'a, str>,
 
     /// Timestamp of this commit.
-    pub timestamp: Dt,
+    pub timestamp: Timestamp,
 
     /// Name and email of the commit author.
     #[serde(borrow)]
@@ -1257,19 +1510,22 @@ pub struct SimpleCommit<'a> {
 }
 
 /// Information about a repository.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct SimpleRepository<'a> {
     /// Numeric Id of this repository.
     pub id: UInt,
 
     /// String identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this repository.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The name including owner ie. `owner/repo-name`.
-    pub full_name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub full_name: Cow<'a, str>,
 
     /// The visibility of this repo.
     #[serde(default)]
@@ -1287,10 +1543,41 @@ pub struct SimpleRepository<'a> {
     pub html_url: Url,
 
     /// The url to the github api of this repo.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// Description of the repo.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// A map of all the github api urls.
+    #[serde(flatten, default)]
+    pub all_urls: UrlMap,
+}
+
+/// Information about an organization, this is what webhook payloads include.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct SimpleOrg<'a> {
+    /// The name of the organization.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub login: Cow<'a, str>,
+
+    /// Numeric identifier of the organization.
+    pub id: UInt,
+
+    /// String identifier of the organization.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The url to the organizations github api.
+    pub url: Url,
+
+    /// Url to the organizations avatar image.
+    pub avatar_url: Url,
+
+    /// A description of the organization.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// A map of all the github api urls.
     #[serde(flatten, default)]
@@ -1298,43 +1585,51 @@ pub struct SimpleRepository<'a> {
 }
 
 /// Simple information about a "user".
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct ShortUser<'a> {
     /// Name of the user.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// Email of the user.
-    pub email: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub email: Cow<'a, str>,
 
     /// The date of the event this user is related to happened.
-    pub date: Dt,
+    pub date: Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Team<'a> {
     /// Numeric Id of this team.
     pub id: UInt,
 
     /// String identifier of the team.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this team.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The slug of this team.
-    pub slug: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub slug: Cow<'a, str>,
 
     /// The public web page url.
     pub html_url: Url,
 
     /// The url to the github api of this repo.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// Description of the repo.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// The privacy this team is.
-    pub privacy: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub privacy: Cow<'a, str>,
 
     /// Permissions required for this team.
     #[serde(alias = "permission")]
@@ -1343,19 +1638,18 @@ pub struct Team<'a> {
     /// The title of this team.
     ///
     /// This is optional for the REST api responses.
-    pub title: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub title: Option<Cow<'a, str>>,
 
     /// The number of members on this team.
     #[serde(default)]
     pub members_count: UInt,
 
     /// The time in UTC when the team was created.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub created_at: Option<Dt>,
+    pub created_at: Option<Timestamp>,
 
     /// The time in UTC when the team was last updated.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub updated_at: Option<Dt>,
+    pub updated_at: Option<Timestamp>,
 
     /// An owning organization of this team.
     pub organization: Option<Org<'a>>,
@@ -1372,27 +1666,32 @@ pub struct Team<'a> {
 ///
 /// This can be used for identifying an organization, owner, or
 /// sender.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct User<'a> {
     /// The name of the user.
-    pub login: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub login: Cow<'a, str>,
 
     /// The numeric identifier of this user.
     pub id: UInt,
 
     /// String identifier of the user.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The users avatar url.
     pub avatar_url: Url,
 
-    pub gravatar_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub gravatar_id: Cow<'a, str>,
 
     /// Url to the github api for this user.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// Url to the github webpage of this user.
-    pub html_url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub html_url: Cow<'a, str>,
 
     /// The type of user.
     #[serde(rename = "type")]
@@ -1407,7 +1706,7 @@ pub struct User<'a> {
 }
 
 /// Information about the verification of an object.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Verification<'a> {
     /// Has this object been verified.
     pub verified: bool,
@@ -1415,73 +1714,84 @@ pub struct Verification<'a> {
     /// Reason given about verification.
     ///
     /// "valid" on success, may give an error on failure.
-    pub reason: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub reason: Cow<'a, str>,
 
     /// The PGP signature of this commit.
-    #[serde(borrow)]
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
     pub signature: Option<Cow<'a, str>>,
 
     /// The payload of this commit.
     ///
     /// Often source control specific information.
-    #[serde(borrow)]
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
     pub payload: Option<Cow<'a, str>>,
 }
 
 /// Information about a workflow.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct Workflow<'a> {
     /// The url to this repositories badge.
-    pub badge_url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub badge_url: Cow<'a, str>,
 
     /// The date in UTC when created.
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// The public github url.
-    pub html_url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub html_url: Cow<'a, str>,
 
     /// Numeric identifier of this resource.
     pub id: UInt,
 
     /// The name of the run, this is also the file name.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// String identifier of this resource.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// String identifier of this resource.
-    pub path: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub path: Cow<'a, str>,
 
     /// String identifier of this resource.
-    pub state: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub state: Cow<'a, str>,
 
     /// Date in UTC when last updated.
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// The github api url for this workflow.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 }
 
 /// Information about a workflow run.
-#[derive(Clone, Debug, Deserialize, Incoming)]
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
 pub struct WorkflowRun<'a> {
     /// Numeric identifier for related the check suite.
     pub check_suite_id: Option<UInt>,
 
     /// String identifier of the check suite for this run.
-    pub check_suite_node_id: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub check_suite_node_id: Option<Cow<'a, str>>,
 
     /// The result of the run.
     pub conclusion: Option<ConclusionStatus>,
 
     /// The date in UTC when created.
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// The event that triggered the run.
-    pub event: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub event: Cow<'a, str>,
 
     /// The name of the head branch.
-    pub head_branch: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub head_branch: Cow<'a, str>,
 
     /// The head commit related to this run.
     pub head_commit: SimpleCommit<'a>,
@@ -1490,22 +1800,27 @@ pub struct WorkflowRun<'a> {
     pub head_repository: SimpleRepository<'a>,
 
     /// The SHA related to this commit
-    pub head_sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub head_sha: Cow<'a, str>,
 
     /// The public github web page for the run.
-    pub html_url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub html_url: Cow<'a, str>,
 
     /// Numeric identifier of this resource.
     pub id: UInt,
 
     /// String identifier of this resource.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of the run, this is also the file name.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// Pull requests associated with the run.
-    pub pull_requests: Vec<PullRequest<'a>>,
+    #[serde(borrow)]
+    pub pull_requests: Vec<PullRequestRef<'a>>,
 
     /// Information about the repository,
     ///
@@ -1515,14 +1830,22 @@ pub struct WorkflowRun<'a> {
     /// A numberic identifier for this run.
     pub run_number: UInt,
 
+    /// Which attempt of this run this is, starting at 1.
+    pub run_attempt: Option<UInt>,
+
+    /// The user who triggered the run.
+    #[serde(borrow)]
+    pub actor: Option<User<'a>>,
+
     /// The state of the run.
     pub status: CheckStatus,
 
     /// Date in UTC when the workflow or run was updated.
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// The github api url.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// The numberic identifier for this workflow.
     pub workflow_id: UInt,
@@ -1532,6 +1855,60 @@ pub struct WorkflowRun<'a> {
     pub all_urls: UrlMap,
 }
 
+/// The pull request a workflow run is associated with.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct PullRequestRef<'a> {
+    /// Numeric Id of this pull request.
+    pub id: UInt,
+
+    /// The pull request number.
+    pub number: UInt,
+
+    /// The github api url of this pull request.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
+
+    /// The branch the changes are on.
+    #[serde(borrow)]
+    pub head: BranchRef<'a>,
+
+    /// The branch the changes are merged into.
+    #[serde(borrow)]
+    pub base: BranchRef<'a>,
+}
+
+/// The branch and commit of one side of a [`PullRequestRef`].
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct BranchRef<'a> {
+    /// The name of the branch.
+    #[serde(rename = "ref")]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
+
+    /// The SHA of the commit the branch points to.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
+
+    /// The repository the branch belongs to.
+    #[serde(borrow)]
+    pub repo: RepoRef<'a>,
+}
+
+/// The bare minimum to identify a repository.
+#[derive(Clone, Debug, Deserialize, Serialize, Incoming, IntoOwned)]
+pub struct RepoRef<'a> {
+    /// Numeric Id of this repository.
+    pub id: UInt,
+
+    /// The github api url of this repository.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
+
+    /// The name of this repository.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+}
+
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum Field {
@@ -1658,7 +2035,8 @@ impl<'de: 'a, 'a> Deserialize<'de> for Links<'a> {
 
         #[derive(Deserialize)]
         struct Href<'a> {
-            href: Option<&'a str>,
+            #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+            href: Option<Cow<'a, str>>,
         }
 
         struct LinksVisitor<'a>(std::marker::PhantomData<&'a ()>);
@@ -1751,6 +2129,71 @@ impl<'de: 'a, 'a> Deserialize<'de> for Links<'a> {
     }
 }
 
+impl Serialize for IncomingLinks {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_links(
+            ser,
+            [
+                ("self", self.self_link.as_deref()),
+                ("html", self.html_link.as_deref()),
+                ("issue", self.issue_link.as_deref()),
+                ("comments", self.comments_link.as_deref()),
+                ("review_comments", self.review_comments_link.as_deref()),
+                ("review_comment", self.review_comment_link.as_deref()),
+                ("commits", self.commits_link.as_deref()),
+                ("statuses", self.statuses_link.as_deref()),
+                ("pull_request", self.pull_request_link.as_deref()),
+            ],
+        )
+    }
+}
+
+impl Serialize for Links<'_> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_links(
+            ser,
+            [
+                ("self", self.self_link.as_deref()),
+                ("html", self.html_link.as_deref()),
+                ("issue", self.issue_link.as_deref()),
+                ("comments", self.comments_link.as_deref()),
+                ("review_comments", self.review_comments_link.as_deref()),
+                ("review_comment", self.review_comment_link.as_deref()),
+                ("commits", self.commits_link.as_deref()),
+                ("statuses", self.statuses_link.as_deref()),
+                ("pull_request", self.pull_request_link.as_deref()),
+            ],
+        )
+    }
+}
+
+/// Serialize links in the same `{ "self": { "href": "..." } }` form github sends.
+fn serialize_links<S: serde::Serializer>(
+    ser: S,
+    links: [(&str, Option<&str>); 9],
+) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeMap;
+
+    #[derive(Serialize)]
+    struct Href<'a> {
+        href: &'a str,
+    }
+
+    let mut map = ser.serialize_map(None)?;
+    for (key, link) in links.iter() {
+        if let Some(href) = link {
+            map.serialize_entry(key, &Href { href })?;
+        }
+    }
+    map.end()
+}
+
 fn set_field<T, E: serde::de::Error>(
     field: &mut Option<T>,
     value: Option<T>,
@@ -0,0 +1,285 @@
+//! Checks every webhook fixture against the payload types.
+//!
+//! Each file in `test_json/` and `test_json/captured/<event>/` is parsed as the event
+//! its name maps to, serialized again and compared to the original JSON. Keys that
+//! are in the fixture but not in the serialized payload were not captured by any
+//! field. Those keys and the parsed payload are compared to the snapshot in
+//! `test_json/snapshots/`, so a payload github changed shows up as a snapshot diff.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1` to write the snapshots instead of comparing them. With
+//! the `strict` feature every fixture is also parsed with [`GitHubEvent::from_raw`],
+//! which has to fail with exactly the keys its snapshot lists:
+//!
+//! ```sh
+//! cargo test -p gitty-hub --features strict fixture_conformance
+//! ```
+
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+#[cfg(feature = "strict")]
+use crate::api::webhooks::WebhookError;
+use crate::api::{
+    webhooks::{unknown_keys, GitHubEvent},
+    EventKind,
+};
+
+/// Fixtures named after the payload rather than the event.
+const ALIASES: &[(&str, &str)] = &[
+    ("issue", "issues"),
+    ("pull", "pull_request"),
+    ("pull_review", "pull_request_review"),
+    ("pull_review_comment", "pull_request_review_comment"),
+];
+
+/// Directories in `test_json/` that are not webhook payloads.
+const SKIP: &[&str] = &["captured", "graphql", "rest", "snapshots"];
+
+/// Finds the event of a fixture from its file name.
+///
+/// `real_` is dropped and then `_` separated suffixes until an event is left, so
+/// `real_pull.json` is a `pull_request` and `issue_comment_escaped.json` is an
+/// `issue_comment`.
+fn kind_of(stem: &str) -> Option<EventKind> {
+    let mut name = stem.strip_prefix("real_").unwrap_or(stem);
+    loop {
+        let alias = ALIASES.iter().find(|(file, _)| *file == name).map(|(_, kind)| *kind);
+        let kind = EventKind::from(alias.unwrap_or(name));
+        if !matches!(kind, EventKind::_Custom(_)) {
+            return Some(kind);
+        }
+        name = &name[..name.rfind('_')?];
+    }
+}
+
+/// Collects every webhook fixture along with the event it is parsed as.
+fn fixtures() -> Vec<(PathBuf, EventKind)> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_json");
+    let mut fixtures = vec![];
+
+    for entry in fs::read_dir(&root).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+            let stem = path.file_stem().unwrap().to_str().unwrap();
+            let kind = kind_of(stem).unwrap_or_else(|| {
+                panic!("no event for {}, add it to `ALIASES`", path.display())
+            });
+            fixtures.push((path, kind));
+        } else if path.is_dir() {
+            let name = path.file_name().unwrap().to_str().unwrap();
+            assert!(SKIP.contains(&name), "unknown fixture directory {}", path.display());
+        }
+    }
+
+    // Captured payloads are kept in a directory named after the `X-GitHub-Event`
+    if let Ok(events) = fs::read_dir(root.join("captured")) {
+        for event in events {
+            let dir = event.unwrap().path();
+            if !dir.is_dir() {
+                continue;
+            }
+            let kind = EventKind::from(dir.file_name().unwrap().to_str().unwrap());
+            for entry in fs::read_dir(&dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+                    fixtures.push((path, kind.clone()));
+                }
+            }
+        }
+    }
+
+    fixtures.sort_by(|(a, _), (b, _)| a.cmp(b));
+    fixtures
+}
+
+/// The snapshot file of a fixture.
+fn snapshot_path(fixture: &Path) -> PathBuf {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_json");
+    let name = fixture.strip_prefix(&root).unwrap().with_extension("snap");
+    root.join("snapshots").join(name.to_str().unwrap().replace('/', "__"))
+}
+
+/// Reads the keys no field captured from a snapshot.
+fn snapshot_unknown(snap: &str) -> Vec<&str> {
+    snap.lines()
+        .skip_while(|line| *line != "# Keys no field captured")
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .collect()
+}
+
+/// Removes the key at `path` from `value`, `key[]` is the key in every element of the
+/// array at `key`.
+fn remove_key(value: &mut Value, path: &str) {
+    let (key, rest) = match path.split_once('.') {
+        Some((key, rest)) => (key, Some(rest)),
+        None => (path, None),
+    };
+    let map = match value.as_object_mut() {
+        Some(map) => map,
+        None => return,
+    };
+    match (key.strip_suffix("[]"), rest) {
+        (Some(key), Some(rest)) => {
+            if let Some(Value::Array(values)) = map.get_mut(key) {
+                values.iter_mut().for_each(|value| remove_key(value, rest));
+            }
+        }
+        (None, Some(rest)) => {
+            if let Some(value) = map.get_mut(key) {
+                remove_key(value, rest);
+            }
+        }
+        (_, None) => {
+            map.remove(key);
+        }
+    }
+}
+
+/// Collects the paths where `parsed` lost or changed a value of `raw`.
+///
+/// Keys only `parsed` has are fields github left out, they serialize as `null` or their
+/// default since a missing field and a `null` one parse to the same value.
+fn differences(raw: &Value, parsed: &Value, path: &str, diffs: &mut Vec<String>) {
+    match (raw, parsed) {
+        (Value::Object(raw), Value::Object(parsed)) => {
+            for (key, raw) in raw {
+                let path = if path.is_empty() {
+                    key.to_owned()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match parsed.get(key) {
+                    Some(parsed) => differences(raw, parsed, &path, diffs),
+                    None => diffs.push(format!("{}: {} → missing", path, raw)),
+                }
+            }
+        }
+        (Value::Array(raw), Value::Array(parsed)) if raw.len() == parsed.len() => {
+            for (i, (raw, parsed)) in raw.iter().zip(parsed).enumerate() {
+                differences(raw, parsed, &format!("{}[{}]", path, i), diffs);
+            }
+        }
+        _ if raw != parsed => diffs.push(format!("{}: {} → {}", path, raw, parsed)),
+        _ => {}
+    }
+}
+
+/// Renders the snapshot of a parsed fixture.
+fn snapshot(unknown: &BTreeSet<String>, parsed: &Value) -> String {
+    let mut snap = String::from("# Keys no field captured\n");
+    for key in unknown {
+        snap.push_str(key);
+        snap.push('\n');
+    }
+    snap.push_str("\n# Parsed payload\n");
+    snap.push_str(&serde_json::to_string_pretty(parsed).unwrap());
+    snap.push('\n');
+    snap
+}
+
+#[test]
+fn fixture_conformance() {
+    let update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut failed = vec![];
+
+    for (path, kind) in fixtures() {
+        let json = fs::read_to_string(&path).unwrap();
+        let event = match GitHubEvent::from_raw_lenient(kind.as_ref(), &json) {
+            Ok(event) => event,
+            // Collect every fixture that fails instead of stopping at the first
+            Err(e) => {
+                failed.push(format!("{}: {}", path.display(), e));
+                continue;
+            }
+        };
+        if let GitHubEvent::Unknown { .. } = event {
+            panic!("{} has no payload type for `{}` events", path.display(), kind);
+        }
+
+        let raw: Value = serde_json::from_str(&json).unwrap();
+        let parsed = serde_json::to_value(&event).unwrap();
+        let mut unknown = BTreeSet::new();
+        unknown_keys(&raw, &parsed, "", &mut unknown);
+
+        #[cfg(feature = "strict")]
+        {
+            let rejected = match GitHubEvent::from_raw(kind.as_ref(), &json) {
+                Ok(_) => vec![],
+                Err(WebhookError::UnknownFields(keys)) => keys,
+                Err(e) => vec![e.to_string()],
+            };
+            if !rejected.iter().eq(&unknown) {
+                failed.push(format!(
+                    "{}: strict parsing rejected {:?} instead of {:?}",
+                    path.display(),
+                    rejected,
+                    unknown
+                ));
+            }
+        }
+
+        let snap_path = snapshot_path(&path);
+        let snap = snapshot(&unknown, &parsed);
+
+        if update {
+            fs::write(&snap_path, snap).unwrap();
+        } else if fs::read_to_string(&snap_path).ok().as_deref() != Some(&*snap) {
+            failed.push(format!("{}: does not match its snapshot", path.display()));
+        }
+    }
+
+    assert!(
+        failed.is_empty(),
+        "these fixtures failed, run with UPDATE_SNAPSHOTS=1 and review the diff of the \
+         snapshots that no longer match:\n{}",
+        failed.join("\n")
+    );
+}
+
+#[test]
+fn fixture_round_trip() {
+    let mut failed = vec![];
+
+    for (path, kind) in fixtures() {
+        let json = fs::read_to_string(&path).unwrap();
+        let event = match GitHubEvent::from_raw_lenient(kind.as_ref(), &json) {
+            Ok(event) => event,
+            // `fixture_conformance` reports fixtures that fail to parse
+            Err(_) => continue,
+        };
+        let first = serde_json::to_string(&event).unwrap();
+
+        // Apart from the keys the snapshot lists as not captured, every value of the
+        // fixture serializes back unchanged
+        let mut raw: Value = serde_json::from_str(&json).unwrap();
+        let snap = fs::read_to_string(snapshot_path(&path)).unwrap_or_default();
+        for key in snapshot_unknown(&snap) {
+            remove_key(&mut raw, key);
+        }
+        let mut diffs = vec![];
+        differences(&raw, &serde_json::from_str(&first).unwrap(), "", &mut diffs);
+        if !diffs.is_empty() {
+            failed.push(format!("{}:\n    {}", path.display(), diffs.join("\n    ")));
+        }
+
+        let reparsed = GitHubEvent::from_raw(kind.as_ref(), &first).unwrap_or_else(|e| {
+            panic!("{} failed to parse again: {}", path.display(), e)
+        });
+        let second = serde_json::to_string(&reparsed).unwrap();
+        if first != second {
+            failed.push(format!("{}: changes when parsed again", path.display()));
+        }
+    }
+
+    assert!(
+        failed.is_empty(),
+        "these fixtures do not serialize back to the JSON they were parsed from:\n{}",
+        failed.join("\n")
+    );
+}
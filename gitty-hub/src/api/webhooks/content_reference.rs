@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, User},
+    utils::deserialize_cow_str,
+};
+
+/// The payload of a content reference event.
+///
+/// This is sent to github apps when a link to one of their registered domains is
+/// posted in an issue or pull request.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ContentReferenceEvent<'a> {
+    /// The action that was performed.
+    pub action: ContentReferenceAction,
+
+    /// The link that was posted.
+    #[serde(borrow)]
+    pub content_reference: ContentReference<'a>,
+
+    /// Detailed information about the repository the link was posted in.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who posted the link.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a content reference event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ContentReferenceAction {
+    /// A link was posted.
+    Created,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// A link to a domain registered by a github app.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ContentReference<'a> {
+    /// Numeric Id of this content reference.
+    pub id: UInt,
+
+    /// String identifier of the content reference.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The link that was posted.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub reference: Cow<'a, str>,
+}
@@ -1,26 +1,35 @@
-use github_derive::StringEnum;
-use serde::Deserialize;
+use std::borrow::Cow;
 
-use crate::api::{Installation, Org, Repository, User};
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
 
 /// The payload of a create event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct CreateEvent<'a> {
     /// The action that was performed.
     #[serde(rename = "ref")]
-    pub ref_: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
 
     /// The type of git object created in the repository.
     pub ref_type: RefType,
 
     /// The name of the repositories master branch.
-    pub master_branch: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub master_branch: Cow<'a, str>,
 
     /// The repositories current description.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// The pusher type for the event.
-    pub pusher_type: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub pusher_type: Cow<'a, str>,
 
     /// Information about the repositories this app has access to.
     #[serde(borrow)]
@@ -29,7 +38,7 @@ pub struct CreateEvent<'a> {
     /// Detailed information about the organization the app
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -52,4 +61,7 @@ pub enum RefType {
 
     /// A branch git object was created.
     Branch,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
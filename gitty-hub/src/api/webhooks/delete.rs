@@ -1,19 +1,27 @@
-use serde::Deserialize;
+use std::borrow::Cow;
 
-use crate::api::{webhooks::create::RefType, Installation, Org, Repository, User};
+use github_derive::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{webhooks::create::RefType, Installation, Repository, SimpleOrg, User},
+    utils::deserialize_cow_str,
+};
 
 /// The payload of a delete event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct DeleteEvent<'a> {
     /// The action that was performed.
     #[serde(rename = "ref")]
-    pub ref_: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
 
     /// The type of git object deleted in the repository.
     pub ref_type: RefType,
 
     /// The pusher type for the event.
-    pub pusher_type: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub pusher_type: Cow<'a, str>,
 
     /// Information about the repositories this app has access to.
     #[serde(borrow)]
@@ -22,7 +30,7 @@ pub struct DeleteEvent<'a> {
     /// Detailed information about the organization the app
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -0,0 +1,157 @@
+//! The metadata github sends in the headers of every webhook delivery.
+//!
+//! Apart from the event name none of these are needed to parse the payload, they
+//! identify the delivery and the hook that sent it.
+
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+
+use crate::api::webhooks::{GitHubEvent, WebhookError};
+
+/// The header carrying the GUID of a delivery, redeliveries keep the same GUID.
+pub const X_GITHUB_DELIVERY: &str = "x-github-delivery";
+
+/// The header carrying the name of the event, ie. `pull_request`.
+pub const X_GITHUB_EVENT: &str = "x-github-event";
+
+/// The header carrying the numeric Id of the hook that sent the delivery.
+pub const X_GITHUB_HOOK_ID: &str = "x-github-hook-id";
+
+/// The header carrying what the hook was created on.
+pub const X_GITHUB_HOOK_TARGET_TYPE: &str = "x-github-hook-installation-target-type";
+
+/// The header carrying the numeric Id of what the hook was created on.
+pub const X_GITHUB_HOOK_TARGET_ID: &str = "x-github-hook-installation-target-id";
+
+/// The header carrying `GitHub-Hookshot/<version>`.
+pub const USER_AGENT: &str = "user-agent";
+
+/// A parsed webhook event along with the metadata of its delivery.
+#[derive(Clone, Debug, IntoOwned)]
+pub struct WebhookDelivery<'req> {
+    /// The GUID of this delivery from `X-GitHub-Delivery`.
+    pub id: Option<Cow<'req, str>>,
+
+    /// The numeric Id of the hook that sent this delivery from `X-GitHub-Hook-ID`.
+    pub hook_id: Option<UInt>,
+
+    /// What the hook that sent this delivery was created on.
+    pub target: Option<HookTarget>,
+
+    /// The `User-Agent` of the delivery, ie. `GitHub-Hookshot/044aadd`.
+    pub user_agent: Option<Cow<'req, str>>,
+
+    /// The parsed event.
+    pub event: GitHubEvent<'req>,
+}
+
+impl<'req> WebhookDelivery<'req> {
+    /// Parses the payload of a delivery and collects the metadata from its headers.
+    ///
+    /// `header` looks up a request header by its lowercase name. Only
+    /// `X-GitHub-Event` is required, the other headers are `None` when missing or
+    /// malformed.
+    pub fn from_headers<F>(header: F, body: &'req str) -> Result<Self, WebhookError>
+    where
+        F: Fn(&str) -> Option<&'req str>,
+    {
+        let kind =
+            header(X_GITHUB_EVENT).ok_or(WebhookError::MissingHeader(X_GITHUB_EVENT))?;
+        let target = match (
+            header(X_GITHUB_HOOK_TARGET_TYPE),
+            header(X_GITHUB_HOOK_TARGET_ID).and_then(|id| id.parse().ok()),
+        ) {
+            (Some(kind), Some(id)) => Some(HookTarget { kind: kind.into(), id }),
+            _ => None,
+        };
+
+        Ok(Self {
+            id: header(X_GITHUB_DELIVERY).map(Cow::Borrowed),
+            hook_id: header(X_GITHUB_HOOK_ID).and_then(|id| id.parse().ok()),
+            target,
+            user_agent: header(USER_AGENT).map(Cow::Borrowed),
+            event: GitHubEvent::from_raw(kind, body)?,
+        })
+    }
+}
+
+/// What the hook that sent a delivery was created on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookTarget {
+    /// The kind of target.
+    pub kind: TargetType,
+
+    /// The numeric Id of the repository, organization, app etc.
+    pub id: UInt,
+}
+
+/// The kind of thing a hook was created on.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TargetType {
+    /// A repository hook.
+    Repository,
+
+    /// An organization hook.
+    Organization,
+
+    /// A github app hook.
+    Integration,
+
+    /// An enterprise hook.
+    Business,
+
+    /// A github marketplace listing hook.
+    #[github_enum(rename = "marketplace::listing")]
+    MarketplaceListing,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+#[test]
+fn delivery_headers() {
+    use js_int::uint;
+
+    let json = include_str!("../../../test_json/star.json");
+    let header = |name: &str| match name {
+        X_GITHUB_DELIVERY => Some("72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+        X_GITHUB_EVENT => Some("star"),
+        X_GITHUB_HOOK_ID => Some("292430182"),
+        X_GITHUB_HOOK_TARGET_TYPE => Some("repository"),
+        X_GITHUB_HOOK_TARGET_ID => Some("79929171"),
+        USER_AGENT => Some("GitHub-Hookshot/044aadd"),
+        _ => None,
+    };
+
+    let delivery = WebhookDelivery::from_headers(header, json).unwrap();
+    assert_eq!(delivery.id.as_deref(), Some("72d3162e-cc78-11e3-81ab-4c9367dc0958"));
+    assert_eq!(delivery.hook_id, Some(uint!(292_430_182)));
+    assert_eq!(
+        delivery.target,
+        Some(HookTarget { kind: TargetType::Repository, id: uint!(79_929_171) })
+    );
+    assert_eq!(delivery.user_agent.as_deref(), Some("GitHub-Hookshot/044aadd"));
+    assert!(matches!(delivery.event, GitHubEvent::Star(_)));
+}
+
+#[test]
+fn delivery_missing_headers() {
+    let json = include_str!("../../../test_json/star.json");
+
+    let delivery = WebhookDelivery::from_headers(
+        |name| if name == X_GITHUB_EVENT { Some("star") } else { None },
+        json,
+    )
+    .unwrap();
+    assert!(delivery.id.is_none());
+    assert!(delivery.hook_id.is_none());
+    assert!(delivery.target.is_none());
+
+    let err = WebhookDelivery::from_headers(|_| None, json).unwrap_err();
+    assert!(matches!(err, WebhookError::MissingHeader(X_GITHUB_EVENT)));
+    assert!(err.path().is_none());
+}
@@ -0,0 +1,242 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{AlertSeverity, Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a dependabot alert event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DependabotAlertEvent<'a> {
+    /// The action that was performed.
+    pub action: DependabotAlertAction,
+
+    /// The dependabot alert.
+    #[serde(borrow)]
+    pub alert: DependabotAlert<'a>,
+
+    /// Detailed information about the repository the alert belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the event.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a dependabot alert event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DependabotAlertAction {
+    /// A vulnerable dependency was found.
+    Created,
+
+    /// Someone dismissed a dependabot alert.
+    Dismissed,
+
+    /// Someone reopened a dependabot alert.
+    Reopened,
+
+    /// The vulnerable dependency was updated or removed.
+    Fixed,
+
+    /// A previously fixed vulnerable dependency was added back.
+    Reintroduced,
+
+    /// A dependabot alert was dismissed by an auto-triage rule.
+    AutoDismissed,
+
+    /// A dependabot alert was reopened by an auto-triage rule.
+    AutoReopened,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a dependabot alert.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DependabotAlert<'a> {
+    /// The number of this alert within the repository.
+    pub number: UInt,
+
+    /// The state of this alert.
+    pub state: DependabotAlertState,
+
+    /// The vulnerable dependency.
+    #[serde(borrow)]
+    pub dependency: Dependency<'a>,
+
+    /// The advisory the alert was raised for.
+    #[serde(borrow)]
+    pub security_advisory: SecurityAdvisory<'a>,
+
+    /// The vulnerability of the dependency's package.
+    #[serde(borrow)]
+    pub security_vulnerability: SecurityVulnerability<'a>,
+
+    /// The user who dismissed the alert.
+    #[serde(borrow)]
+    pub dismissed_by: Option<User<'a>>,
+
+    /// The reason the alert was dismissed, ie. `tolerable_risk`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub dismissed_reason: Option<Cow<'a, str>>,
+
+    /// The comment left when the alert was dismissed.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub dismissed_comment: Option<Cow<'a, str>>,
+
+    /// The time in UTC when the alert was dismissed.
+    pub dismissed_at: Option<Timestamp>,
+
+    /// The time in UTC when the alert was fixed.
+    pub fixed_at: Option<Timestamp>,
+
+    /// The time in UTC when the alert was dismissed by an auto-triage rule.
+    pub auto_dismissed_at: Option<Timestamp>,
+
+    /// The github api url of this alert.
+    pub url: Url,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The time in UTC when the alert was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the alert was updated.
+    pub updated_at: Timestamp,
+}
+
+impl DependabotAlert<'_> {
+    /// Whether this alert still needs attention.
+    pub fn is_open(&self) -> bool { self.state == DependabotAlertState::Open }
+}
+
+/// The state of a dependabot alert.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DependabotAlertState {
+    /// The alert is open.
+    Open,
+
+    /// The alert was dismissed by a user.
+    Dismissed,
+
+    /// The vulnerable dependency was updated or removed.
+    Fixed,
+
+    /// The alert was dismissed by an auto-triage rule.
+    AutoDismissed,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// A dependency of a repository.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Dependency<'a> {
+    /// The package that is depended on.
+    #[serde(borrow)]
+    pub package: Package<'a>,
+
+    /// The path of the manifest the dependency is declared in, ie. `Cargo.lock`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub manifest_path: Cow<'a, str>,
+
+    /// Whether the dependency is used at runtime or only during development.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub scope: Option<Cow<'a, str>>,
+}
+
+/// A package of a package ecosystem.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Package<'a> {
+    /// The package ecosystem, ie. `rust` or `npm`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ecosystem: Cow<'a, str>,
+
+    /// The name of the package.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+}
+
+/// A github security advisory.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct SecurityAdvisory<'a> {
+    /// The GHSA identifier of the advisory, ie. `GHSA-rf4j-j272-fj86`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ghsa_id: Cow<'a, str>,
+
+    /// The CVE identifier of the advisory.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub cve_id: Option<Cow<'a, str>>,
+
+    /// A short description of the advisory.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub summary: Cow<'a, str>,
+
+    /// A long description of the advisory.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub description: Cow<'a, str>,
+
+    /// The severity of the advisory.
+    pub severity: AlertSeverity,
+
+    /// The vulnerabilities the advisory covers.
+    #[serde(default, borrow)]
+    pub vulnerabilities: Vec<SecurityVulnerability<'a>>,
+
+    /// The time in UTC when the advisory was published.
+    pub published_at: Timestamp,
+
+    /// The time in UTC when the advisory was updated.
+    pub updated_at: Timestamp,
+
+    /// The time in UTC when the advisory was withdrawn.
+    pub withdrawn_at: Option<Timestamp>,
+}
+
+/// A vulnerable version range of a package.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct SecurityVulnerability<'a> {
+    /// The vulnerable package.
+    #[serde(borrow)]
+    pub package: Package<'a>,
+
+    /// The severity of the vulnerability.
+    pub severity: AlertSeverity,
+
+    /// The vulnerable versions, ie. `< 0.2.1`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub vulnerable_version_range: Cow<'a, str>,
+
+    /// The first version that is not vulnerable.
+    #[serde(borrow)]
+    pub first_patched_version: Option<PatchedVersion<'a>>,
+}
+
+/// The first version of a package that fixes a vulnerability.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PatchedVersion<'a> {
+    /// The version, ie. `0.2.1`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub identifier: Cow<'a, str>,
+}
@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::deserialize_cow_str,
+};
+
+/// The payload of a deploy key event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DeployKeyEvent<'a> {
+    /// The action that was performed.
+    pub action: DeployKeyAction,
+
+    /// The deploy key that was added or removed.
+    #[serde(borrow)]
+    pub key: DeployKey<'a>,
+
+    /// Detailed information about the repository the key belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the key.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a deploy key event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeployKeyAction {
+    /// A deploy key was added to a repository.
+    Created,
+
+    /// A deploy key was removed from a repository.
+    Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a deploy key.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DeployKey<'a> {
+    /// Numeric Id of this key.
+    pub id: UInt,
+
+    /// The public SSH key.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub key: Cow<'a, str>,
+
+    /// The title of this key.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub title: Cow<'a, str>,
+
+    /// Has this key been verified.
+    pub verified: bool,
+
+    /// Can this key only be used to pull.
+    pub read_only: bool,
+
+    /// The github api url of this key.
+    pub url: Url,
+
+    /// The time in UTC when the key was created.
+    pub created_at: Timestamp,
+}
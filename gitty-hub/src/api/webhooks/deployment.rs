@@ -0,0 +1,44 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Deployment, Installation, Repository, SimpleOrg, User};
+
+/// The payload of a deployment event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DeploymentEvent<'a> {
+    /// The action that was performed.
+    pub action: DeploymentAction,
+
+    /// The deployment that was created.
+    #[serde(borrow)]
+    pub deployment: Deployment<'a>,
+
+    /// Detailed information about the repository being deployed.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who created the deployment.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a deployment event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentAction {
+    /// A deployment was created.
+    Created,
+    #[doc(hidden)]
+    _Custom(String),
+}
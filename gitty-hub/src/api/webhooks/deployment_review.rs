@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{
+        ConclusionStatus, Installation, Repository, SimpleOrg, Team, Timestamp, User,
+        WorkflowRun,
+    },
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a deployment review event, this is sent when a deployment to a
+/// protected environment waits for or gets a review.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DeploymentReviewEvent<'a> {
+    /// The action that was performed.
+    pub action: DeploymentReviewAction,
+
+    /// The environment that is waiting for a review.
+    ///
+    /// This is only present when the action is `requested`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub environment: Option<Cow<'a, str>>,
+
+    /// The user who triggered the deployment.
+    ///
+    /// This is only present when the action is `requested`.
+    #[serde(borrow)]
+    pub requestor: Option<User<'a>>,
+
+    /// The users and teams that can review the deployment.
+    #[serde(default, borrow)]
+    pub reviewers: Vec<DeploymentReviewer<'a>>,
+
+    /// The user who approved or rejected the deployment.
+    #[serde(borrow)]
+    pub approver: Option<User<'a>>,
+
+    /// The comment left with the review.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub comment: Option<Cow<'a, str>>,
+
+    /// The jobs that deploy to the reviewed environments.
+    #[serde(default, borrow)]
+    pub workflow_job_runs: Vec<WorkflowJobRun<'a>>,
+
+    /// The workflow run the deployment belongs to.
+    #[serde(borrow)]
+    pub workflow_run: Option<WorkflowRun<'a>>,
+
+    /// Detailed information about the repository being deployed.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the event.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a deployment review event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentReviewAction {
+    /// A deployment is waiting for a review.
+    Requested,
+
+    /// A deployment was approved.
+    Approved,
+
+    /// A deployment was rejected.
+    Rejected,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// A user or team that can review a deployment.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DeploymentReviewer<'a> {
+    /// Whether the reviewer is a user or a team.
+    #[serde(rename = "type")]
+    pub kind: ReviewerType,
+
+    /// The reviewer.
+    #[serde(borrow)]
+    pub reviewer: Reviewer<'a>,
+}
+
+/// Whether a deployment reviewer is a user or a team.
+#[derive(Clone, Debug, StringEnum)]
+#[non_exhaustive]
+pub enum ReviewerType {
+    /// The reviewer is a user.
+    User,
+
+    /// The reviewer is a team.
+    Team,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// A user or team that can review a deployment.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+#[serde(untagged)]
+pub enum Reviewer<'a> {
+    /// The reviewer is a user.
+    User(#[serde(borrow)] User<'a>),
+
+    /// The reviewer is a team.
+    Team(#[serde(borrow)] Team<'a>),
+}
+
+/// A job that deploys to a reviewed environment.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct WorkflowJobRun<'a> {
+    /// Numeric Id of this job.
+    pub id: UInt,
+
+    /// The name of this job.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// The environment this job deploys to.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub environment: Cow<'a, str>,
+
+    /// The status of this job, ie. `waiting`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub status: Option<Cow<'a, str>>,
+
+    /// The conclusion of this job.
+    pub conclusion: Option<ConclusionStatus>,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The time in UTC when the job was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the job was updated.
+    pub updated_at: Timestamp,
+}
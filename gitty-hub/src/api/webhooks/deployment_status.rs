@@ -0,0 +1,50 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    Deployment, DeploymentStatus, Installation, Repository, SimpleOrg, User,
+};
+
+/// The payload of a deployment status event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DeploymentStatusEvent<'a> {
+    /// The action that was performed.
+    pub action: DeploymentStatusAction,
+
+    /// The status that was created.
+    #[serde(borrow)]
+    pub deployment_status: DeploymentStatus<'a>,
+
+    /// The deployment the status belongs to.
+    #[serde(borrow)]
+    pub deployment: Deployment<'a>,
+
+    /// Detailed information about the repository being deployed.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who created the status.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a deployment status event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentStatusAction {
+    /// A deployment status was created.
+    Created,
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -0,0 +1,323 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{
+        AuthorAssociation, Body, Installation, Label, Repository, SimpleOrg, Timestamp,
+        User,
+    },
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a discussion event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DiscussionEvent<'a> {
+    /// The action that was performed.
+    pub action: DiscussionAction,
+
+    /// The discussion that was changed.
+    #[serde(borrow)]
+    pub discussion: Discussion<'a>,
+
+    /// The comment that was chosen as the answer.
+    ///
+    /// This is only present when the action is `answered`.
+    #[serde(borrow)]
+    pub answer: Option<DiscussionComment<'a>>,
+
+    /// The label that was added or removed.
+    ///
+    /// This is only present when the action is `labeled` or `unlabeled`.
+    #[serde(borrow)]
+    pub label: Option<Label<'a>>,
+
+    /// Changes are only present if the action was edited, category_changed or
+    /// transferred.
+    #[serde(borrow)]
+    pub changes: Option<DiscussionChanges<'a>>,
+
+    /// Detailed information about the repository the discussion belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the discussion.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a discussion event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DiscussionAction {
+    /// A discussion was created.
+    Created,
+
+    /// The title or body of a discussion was edited.
+    Edited,
+
+    /// A discussion was deleted.
+    Deleted,
+
+    /// A discussion was pinned to the top of the discussions page.
+    Pinned,
+
+    /// A discussion was unpinned.
+    Unpinned,
+
+    /// A discussion was locked.
+    Locked,
+
+    /// A discussion was unlocked.
+    Unlocked,
+
+    /// A discussion was moved to another repository.
+    Transferred,
+
+    /// A discussion was moved to another category.
+    CategoryChanged,
+
+    /// A comment was marked as the answer to a discussion.
+    Answered,
+
+    /// The answer of a discussion was unmarked.
+    Unanswered,
+
+    /// A label was added to a discussion.
+    Labeled,
+
+    /// A label was removed from a discussion.
+    Unlabeled,
+
+    /// A discussion was closed.
+    Closed,
+
+    /// A discussion was reopened.
+    Reopened,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a discussion.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Discussion<'a> {
+    /// Numeric Id of this discussion.
+    pub id: UInt,
+
+    /// String identifier of the discussion.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The number of this discussion.
+    pub number: UInt,
+
+    /// The title of this discussion.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub title: Cow<'a, str>,
+
+    /// The body of this discussion.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub body: Option<Cow<'a, str>>,
+
+    /// The user who started the discussion.
+    #[serde(borrow)]
+    pub user: User<'a>,
+
+    /// The category this discussion is in.
+    #[serde(borrow)]
+    pub category: DiscussionCategory<'a>,
+
+    /// The state of this discussion.
+    pub state: DiscussionState,
+
+    /// Is this discussion locked.
+    pub locked: bool,
+
+    /// The number of comments on this discussion.
+    pub comments: UInt,
+
+    /// The association of the author to the repository.
+    pub author_association: AuthorAssociation,
+
+    /// The reason this discussion was locked.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub active_lock_reason: Option<Cow<'a, str>>,
+
+    /// The public web page of the comment that answers this discussion.
+    ///
+    /// This is only present for discussions in an answerable category.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub answer_html_url: Option<Cow<'a, str>>,
+
+    /// The time in UTC when the answer was chosen.
+    pub answer_chosen_at: Option<Timestamp>,
+
+    /// The user who chose the answer.
+    #[serde(borrow)]
+    pub answer_chosen_by: Option<User<'a>>,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The github api url of the repository the discussion belongs to.
+    pub repository_url: Url,
+
+    /// The time in UTC when the discussion was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the discussion was updated.
+    pub updated_at: Timestamp,
+}
+
+impl Discussion<'_> {
+    /// Whether an answer has been chosen for this discussion.
+    pub fn is_answered(&self) -> bool { self.answer_html_url.is_some() }
+}
+
+/// The state of a discussion.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum DiscussionState {
+    /// The discussion is open.
+    Open,
+
+    /// The discussion was closed.
+    Closed,
+
+    /// The discussion was locked.
+    Locked,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// A category of discussions, ie. `Q&A` or `Ideas`.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DiscussionCategory<'a> {
+    /// Numeric Id of this category.
+    pub id: UInt,
+
+    /// String identifier of the category.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub node_id: Option<Cow<'a, str>>,
+
+    /// Numeric Id of the repository this category belongs to.
+    pub repository_id: UInt,
+
+    /// The emoji of this category, ie. `:pray:`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub emoji: Cow<'a, str>,
+
+    /// The name of this category.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The description of this category.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// The slug of this category.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub slug: Cow<'a, str>,
+
+    /// Can a comment be marked as the answer to discussions in this category.
+    pub is_answerable: bool,
+
+    /// The time in UTC when the category was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the category was updated.
+    pub updated_at: Timestamp,
+}
+
+/// A comment on a discussion.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DiscussionComment<'a> {
+    /// Numeric Id of this comment.
+    pub id: UInt,
+
+    /// String identifier of the comment.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The body of this comment.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub body: Cow<'a, str>,
+
+    /// The user who wrote the comment.
+    #[serde(borrow)]
+    pub user: User<'a>,
+
+    /// The association of the author to the repository.
+    pub author_association: AuthorAssociation,
+
+    /// Numeric Id of the comment this is a reply to.
+    pub parent_id: Option<UInt>,
+
+    /// The number of replies to this comment.
+    #[serde(default)]
+    pub child_comment_count: UInt,
+
+    /// Numeric Id of the discussion this comment belongs to.
+    pub discussion_id: Option<UInt>,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The github api url of the repository the discussion belongs to.
+    pub repository_url: Url,
+
+    /// The time in UTC when the comment was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the comment was updated.
+    pub updated_at: Timestamp,
+}
+
+/// The changes made to a discussion or discussion comment.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DiscussionChanges<'a> {
+    /// The previous title of the discussion.
+    #[serde(borrow)]
+    pub title: Option<Body<'a>>,
+
+    /// The previous body of the discussion or comment.
+    #[serde(borrow)]
+    pub body: Option<Body<'a>>,
+
+    /// The previous category of the discussion.
+    #[serde(borrow)]
+    pub category: Option<CategoryChange<'a>>,
+
+    /// The discussion in the repository it was transferred to.
+    #[serde(borrow)]
+    pub new_discussion: Option<Box<Discussion<'a>>>,
+
+    /// The repository the discussion was transferred to.
+    #[serde(borrow)]
+    pub new_repository: Option<Box<Repository<'a>>>,
+}
+
+/// The previous category of a discussion.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct CategoryChange<'a> {
+    /// The category the discussion was moved out of.
+    #[serde(borrow)]
+    pub from: DiscussionCategory<'a>,
+}
@@ -0,0 +1,62 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    webhooks::discussion::{Discussion, DiscussionChanges, DiscussionComment},
+    Installation, Repository, SimpleOrg, User,
+};
+
+/// The payload of a discussion comment event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct DiscussionCommentEvent<'a> {
+    /// The action that was performed.
+    pub action: DiscussionCommentAction,
+
+    /// The comment that was changed.
+    #[serde(borrow)]
+    pub comment: DiscussionComment<'a>,
+
+    /// The discussion the comment belongs to.
+    #[serde(borrow)]
+    pub discussion: Discussion<'a>,
+
+    /// Changes are only present if the action was edited.
+    #[serde(borrow)]
+    pub changes: Option<DiscussionChanges<'a>>,
+
+    /// Detailed information about the repository the discussion belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the comment.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a discussion comment event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum DiscussionCommentAction {
+    /// A comment was created.
+    Created,
+
+    /// The body of a comment was edited.
+    Edited,
+
+    /// A comment was deleted.
+    Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -0,0 +1,30 @@
+use github_derive::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Installation, Repository, SimpleOrg, User};
+
+/// The payload of a fork event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ForkEvent<'a> {
+    /// The newly created fork.
+    #[serde(borrow)]
+    pub forkee: Repository<'a>,
+
+    /// Detailed information about the repository that was forked.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who forked the repo.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a gollum event, this is sent when a wiki page is created or
+/// updated.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct GollumEvent<'a> {
+    /// The pages that were created or updated.
+    #[serde(borrow)]
+    pub pages: Vec<WikiPage<'a>>,
+
+    /// Detailed information about the repository the wiki belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the wiki.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// A wiki page that was changed.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct WikiPage<'a> {
+    /// The name of the page.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub page_name: Cow<'a, str>,
+
+    /// The title of the page.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub title: Cow<'a, str>,
+
+    /// The edit message of the change.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub summary: Option<Cow<'a, str>>,
+
+    /// What was done to the page.
+    pub action: WikiPageAction,
+
+    /// The SHA of the latest commit of the page.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
+
+    /// The public web page url.
+    pub html_url: Url,
+}
+
+/// What was done to a wiki page.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum WikiPageAction {
+    /// The page was created.
+    Created,
+
+    /// The page was edited.
+    Edited,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -1,11 +1,16 @@
-use github_derive::StringEnum;
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::api::{Installation, Org, User};
+use crate::{
+    api::{Installation, SimpleOrg, User},
+    utils::deserialize_cow_str,
+};
 
 /// The payload of an installation event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct InstallationEvent<'a> {
     /// The action that was performed.
     pub action: InstallationAction,
@@ -20,7 +25,7 @@ pub struct InstallationEvent<'a> {
 
     /// Detailed information about the organization the app
     /// belongs to.
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user of the app.
     #[serde(borrow)]
@@ -49,22 +54,28 @@ pub enum InstallationAction {
 
     /// Someone accepts new permissions for a GitHub App installation.
     NewPermissionsAccepted,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// Information about repositories that the installation can access.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct ShortRepo<'a> {
     /// Numeric Id of this repository.
     pub id: UInt,
 
     /// String identifier of the repository.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this repository.
-    pub name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
 
     /// The full name of this repository ie `owner/repo`.
-    pub full_name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub full_name: Cow<'a, str>,
 
     /// Whether the repository is private or public.
     pub private: bool,
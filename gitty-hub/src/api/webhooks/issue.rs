@@ -1,10 +1,10 @@
-use github_derive::StringEnum;
-use serde::Deserialize;
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
 
-use crate::api::{Changes, Installation, Issue, Label, Org, Repository, User};
+use crate::api::{Body, Diff, Installation, Issue, Label, Repository, SimpleOrg, User};
 
 /// The payload of an issue event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct IssueEvent<'a> {
     /// The action that was performed.
     pub action: IssueAction,
@@ -17,7 +17,7 @@ pub struct IssueEvent<'a> {
     ///
     /// Only present for [`crate::api::common::PullAction::Edited`].
     #[serde(borrow)]
-    pub changes: Option<Changes<'a>>,
+    pub changes: Option<IssueChanges<'a>>,
 
     /// The [`User`] who is assigned this issue.
     #[serde(borrow)]
@@ -40,13 +40,28 @@ pub struct IssueEvent<'a> {
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who stared the repo.
     #[serde(borrow)]
     pub sender: User<'a>,
 }
 
+impl IssueEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default().field("title", changes.title.as_ref(), &*self.issue.title).field(
+            "body",
+            changes.body.as_ref(),
+            &*self.issue.body,
+        )
+    }
+}
+
 /// The actions that can be taken for an issue event.
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "lowercase")]
@@ -99,4 +114,27 @@ pub enum IssueAction {
 
     /// A milestone has been removed from this issue.
     Demilestoned,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The changes made to an issue.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct IssueChanges<'a> {
+    /// The previous title of the issue.
+    #[serde(borrow)]
+    pub title: Option<Body<'a>>,
+
+    /// The previous body of the issue.
+    #[serde(borrow)]
+    pub body: Option<Body<'a>>,
+
+    /// The issue in the repository it was transferred to.
+    #[serde(borrow)]
+    pub new_issue: Option<Box<Issue<'a>>>,
+
+    /// The repository the issue was transferred to.
+    #[serde(borrow)]
+    pub new_repository: Option<Box<Repository<'a>>>,
 }
@@ -1,10 +1,13 @@
-use github_derive::StringEnum;
-use serde::Deserialize;
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
 
-use crate::api::{Changes, Comment, Installation, Issue, Label, Org, Repository, User};
+use crate::api::{
+    Comment, CommentChanges, Diff, Installation, Issue, Label, Repository, SimpleOrg,
+    User,
+};
 
 /// The payload of an issue event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct IssueCommentEvent<'a> {
     /// The action that was performed.
     pub action: IssueCommentAction,
@@ -19,7 +22,7 @@ pub struct IssueCommentEvent<'a> {
 
     /// The changes to the comment if the action was edited.
     #[serde(borrow)]
-    pub changes: Option<Changes<'a>>,
+    pub changes: Option<CommentChanges<'a>>,
 
     /// The [`User`] who is assigned this issue.
     #[serde(borrow)]
@@ -36,7 +39,7 @@ pub struct IssueCommentEvent<'a> {
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -49,6 +52,21 @@ pub struct IssueCommentEvent<'a> {
     pub sender: User<'a>,
 }
 
+impl IssueCommentEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default().field(
+            "body",
+            changes.body.as_ref(),
+            self.comment.body.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
 /// The actions that can be taken for an issue comment event.
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "lowercase")]
@@ -62,4 +80,7 @@ pub enum IssueCommentAction {
 
     /// The issue has been deleted.
     Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
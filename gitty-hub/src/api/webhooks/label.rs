@@ -0,0 +1,89 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Body, Diff, Installation, Label, Repository, SimpleOrg, User};
+
+/// The payload of a label event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct LabelEvent<'a> {
+    /// The action that was performed.
+    pub action: LabelAction,
+
+    /// The label that was changed.
+    #[serde(borrow)]
+    pub label: Label<'a>,
+
+    /// Changes are only present if the action was edited.
+    #[serde(borrow)]
+    pub changes: Option<LabelChanges<'a>>,
+
+    /// Detailed information about the repository the label belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the label.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+impl LabelEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default()
+            .field("name", changes.name.as_ref(), &*self.label.name)
+            .field("color", changes.color.as_ref(), &*self.label.color)
+            .field(
+                "description",
+                changes.description.as_ref(),
+                self.label.description.as_deref().unwrap_or_default(),
+            )
+    }
+}
+
+/// The specific actions that a label event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum LabelAction {
+    /// A label was created.
+    Created,
+
+    /// A label's name, color or description was changed.
+    Edited,
+
+    /// A label was deleted.
+    Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The changes made to a label.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct LabelChanges<'a> {
+    /// The previous name of the label.
+    #[serde(borrow)]
+    pub name: Option<Body<'a>>,
+
+    /// The previous color of the label, ie. `d73a4a`.
+    #[serde(borrow)]
+    pub color: Option<Body<'a>>,
+
+    /// The previous description of the label.
+    #[serde(borrow)]
+    pub description: Option<Body<'a>>,
+}
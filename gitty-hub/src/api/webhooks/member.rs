@@ -0,0 +1,55 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Changes, Installation, Repository, SimpleOrg, User};
+
+/// The payload of a member event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct MemberEvent<'a> {
+    /// The action that was performed.
+    pub action: MemberAction,
+
+    /// The user that was added, removed or edited.
+    #[serde(borrow)]
+    pub member: User<'a>,
+
+    /// Changes are only present if the action was edited.
+    #[serde(borrow)]
+    pub changes: Option<Changes<'a>>,
+
+    /// Detailed information about the repository the collaborator belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the collaborator.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a member event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum MemberAction {
+    /// A user accepted an invitation to become a collaborator.
+    Added,
+
+    /// A collaborator was removed.
+    Removed,
+
+    /// A collaborator's permissions were changed.
+    Edited,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -0,0 +1,64 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Installation, SimpleOrg, Team, User};
+
+/// The payload of a membership event, this is sent when a user is added to or
+/// removed from a team.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct MembershipEvent<'a> {
+    /// The action that was performed.
+    pub action: MembershipAction,
+
+    /// What the membership is of, this is always `team`.
+    pub scope: MembershipScope,
+
+    /// The user who was added or removed.
+    #[serde(borrow)]
+    pub member: User<'a>,
+
+    /// The team the user was added to or removed from.
+    #[serde(borrow)]
+    pub team: Team<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the team belongs to.
+    #[serde(borrow)]
+    pub organization: SimpleOrg<'a>,
+
+    /// Detailed information about the user who changed the membership.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a membership event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MembershipAction {
+    /// A user was added to a team.
+    Added,
+
+    /// A user was removed from a team.
+    Removed,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// What a membership is of.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MembershipScope {
+    /// The membership is of a team.
+    Team,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -1,10 +1,10 @@
-use github_derive::StringEnum;
-use serde::Deserialize;
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
 
-use crate::api::{Changes, Installation, Milestone, Org, Repository, User};
+use crate::api::{Body, Diff, Installation, Milestone, Repository, SimpleOrg, User};
 
 /// The payload of a delete event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct MilestoneEvent<'a> {
     /// The action that was performed.
     pub action: MilestoneAction,
@@ -15,7 +15,7 @@ pub struct MilestoneEvent<'a> {
 
     /// The pusher type for the event.
     #[serde(borrow)]
-    pub changes: Option<Changes<'a>>,
+    pub changes: Option<MilestoneChanges<'a>>,
 
     /// Information about the repositories this app has access to.
     #[serde(borrow)]
@@ -24,7 +24,7 @@ pub struct MilestoneEvent<'a> {
     /// Detailed information about the organization the app
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -37,6 +37,32 @@ pub struct MilestoneEvent<'a> {
     pub sender: User<'a>,
 }
 
+impl MilestoneEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default()
+            .field("title", changes.title.as_ref(), &*self.milestone.title)
+            .field(
+                "description",
+                changes.description.as_ref(),
+                self.milestone.description.as_deref().unwrap_or_default(),
+            )
+            .field(
+                "due on",
+                changes.due_on.as_ref(),
+                self.milestone
+                    .due_on
+                    .as_ref()
+                    .map(|due| due.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+                    .unwrap_or_default(),
+            )
+    }
+}
+
 /// The action that was performed on the milestone.
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "lowercase")]
@@ -56,4 +82,23 @@ pub enum MilestoneAction {
 
     /// The milestone was deleted.
     Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The changes made to a milestone.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct MilestoneChanges<'a> {
+    /// The previous title of the milestone.
+    #[serde(borrow)]
+    pub title: Option<Body<'a>>,
+
+    /// The previous description of the milestone.
+    #[serde(borrow)]
+    pub description: Option<Body<'a>>,
+
+    /// The previous due date of the milestone.
+    #[serde(borrow)]
+    pub due_on: Option<Body<'a>>,
 }
@@ -0,0 +1,44 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Installation, SimpleOrg, User};
+
+/// The payload of an org block event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct OrgBlockEvent<'a> {
+    /// The action that was performed.
+    pub action: OrgBlockAction,
+
+    /// The user who was blocked or unblocked.
+    #[serde(borrow)]
+    pub blocked_user: User<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization that blocked the user.
+    #[serde(borrow)]
+    pub organization: SimpleOrg<'a>,
+
+    /// Detailed information about the user who blocked the user.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that an org block event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum OrgBlockAction {
+    /// A user was blocked from the organization.
+    Blocked,
+
+    /// A user was unblocked.
+    Unblocked,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Changes, Installation, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of an organization event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct OrganizationEvent<'a> {
+    /// The action that was performed.
+    pub action: OrganizationAction,
+
+    /// The membership between the user and the organization.
+    ///
+    /// This is not present when the action is `member_invited`.
+    #[serde(borrow)]
+    pub membership: Option<Membership<'a>>,
+
+    /// The invitation that was sent to the user.
+    ///
+    /// This is only present when the action is `member_invited`.
+    #[serde(borrow)]
+    pub invitation: Option<Invitation<'a>>,
+
+    /// Changes are only present if the action was renamed.
+    #[serde(borrow)]
+    pub changes: Option<Changes<'a>>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization.
+    #[serde(borrow)]
+    pub organization: SimpleOrg<'a>,
+
+    /// Detailed information about the user who changed the organization.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that an organization event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum OrganizationAction {
+    /// An organization was deleted.
+    Deleted,
+
+    /// An organization was renamed.
+    Renamed,
+
+    /// A user accepted an invitation to the organization.
+    MemberAdded,
+
+    /// A member was removed from the organization.
+    MemberRemoved,
+
+    /// A user was invited to the organization.
+    MemberInvited,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The membership of a user in an organization.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Membership<'a> {
+    /// The github api url of this membership.
+    pub url: Url,
+
+    /// Whether the user has accepted the invitation yet.
+    pub state: MembershipState,
+
+    /// The role of the user in the organization.
+    pub role: MembershipRole,
+
+    /// The github api url of the organization.
+    pub organization_url: Url,
+
+    /// The member.
+    #[serde(borrow)]
+    pub user: User<'a>,
+}
+
+/// The state of an organization membership.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum MembershipState {
+    /// The user is a member.
+    Active,
+
+    /// The user has not accepted the invitation yet.
+    Pending,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The role of a user in an organization.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MembershipRole {
+    /// An owner of the organization.
+    Admin,
+
+    /// A regular member of the organization.
+    Member,
+
+    /// A user that manages the billing settings of the organization.
+    BillingManager,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// An invitation to join an organization.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Invitation<'a> {
+    /// Numeric Id of this invitation.
+    pub id: UInt,
+
+    /// String identifier of the invitation.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub node_id: Option<Cow<'a, str>>,
+
+    /// The login of the invited user, this is not present for invitations by email.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub login: Option<Cow<'a, str>>,
+
+    /// The email the invitation was sent to.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub email: Option<Cow<'a, str>>,
+
+    /// The role the user will have, ie. `direct_member` or `admin`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub role: Cow<'a, str>,
+
+    /// The time in UTC when the invitation was sent.
+    pub created_at: Option<Timestamp>,
+
+    /// The user who sent the invitation.
+    #[serde(borrow)]
+    pub inviter: Option<User<'a>>,
+
+    /// The number of teams the user is invited to.
+    pub team_count: Option<UInt>,
+}
@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::deserialize_cow_str_opt,
+};
+
+/// The payload of a page build event, this is sent when a github pages site is
+/// built.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PageBuildEvent<'a> {
+    /// Numeric Id of the page build.
+    pub id: UInt,
+
+    /// The page build.
+    #[serde(borrow)]
+    pub build: PageBuild<'a>,
+
+    /// Detailed information about the repository the site is built from.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the build.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// Information about a github pages build.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PageBuild<'a> {
+    /// The github api url of this build.
+    pub url: Url,
+
+    /// The status of this build.
+    pub status: PageBuildStatus,
+
+    /// The error of a failed build.
+    #[serde(borrow)]
+    pub error: PageBuildError<'a>,
+
+    /// The user who pushed the commit that was built.
+    #[serde(borrow)]
+    pub pusher: Option<User<'a>>,
+
+    /// The SHA of the commit that was built.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub commit: Option<Cow<'a, str>>,
+
+    /// How long the build took in milliseconds.
+    pub duration: UInt,
+
+    /// The time in UTC when the build was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the build was updated.
+    pub updated_at: Timestamp,
+}
+
+/// The status of a github pages build.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PageBuildStatus {
+    /// The build is waiting to start.
+    Queued,
+
+    /// The build is running.
+    Building,
+
+    /// The site was built.
+    Built,
+
+    /// The build failed.
+    Errored,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The error of a github pages build.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PageBuildError<'a> {
+    /// The error message, this is `None` if the build did not fail.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub message: Option<Cow<'a, str>>,
+}
@@ -0,0 +1,194 @@
+//! The fields most webhook payloads share.
+//!
+//! Github sends the repository, sender, organization and installation with nearly
+//! every event, [`WebhookPayload`] gives access to them without matching on the
+//! event first.
+
+use crate::api::{
+    webhooks::{
+        check_run::CheckRunEvent, check_suite::CheckSuiteEvent,
+        code_scanning_alert::CodeScanningAlertEvent, commit_comment::CommitCommentEvent,
+        content_reference::ContentReferenceEvent, create::CreateEvent,
+        delete::DeleteEvent, dependabot_alert::DependabotAlertEvent,
+        deploy_key::DeployKeyEvent, deployment::DeploymentEvent,
+        deployment_review::DeploymentReviewEvent,
+        deployment_status::DeploymentStatusEvent, discussion::DiscussionEvent,
+        discussion_comment::DiscussionCommentEvent, fork::ForkEvent, gollum::GollumEvent,
+        installation::InstallationEvent, issue::IssueEvent,
+        issue_comment::IssueCommentEvent, label::LabelEvent, member::MemberEvent,
+        membership::MembershipEvent, milestone::MilestoneEvent, org_block::OrgBlockEvent,
+        organization::OrganizationEvent, page_build::PageBuildEvent, ping::PingEvent,
+        project::ProjectEvent, project_card::ProjectCardEvent,
+        project_column::ProjectColumnEvent, public::PublicEvent, pull::PullRequestEvent,
+        pull_review::PullRequestReviewEvent,
+        pull_review_comment::PullRequestReviewCommentEvent, push::PushEvent,
+        registry_package::RegistryPackageEvent, release::ReleaseEvent,
+        repository::RepositoryEvent, repository_dispatch::RepositoryDispatchEvent,
+        repository_vulnerability_alert::RepositoryVulnerabilityAlertEvent,
+        secret_scanning_alert::SecretScanningAlertEvent, star::StarEvent,
+        status::StatusEvent, team::TeamEvent, team_add::TeamAddEvent, watch::WatchEvent,
+        workflow_dispatch::WorkflowDispatchEvent, workflow_job::WorkflowJobEvent,
+        workflow_run::WorkflowRunEvent, GitHubEvent,
+    },
+    Installation, Repository, SimpleOrg, User,
+};
+
+/// Access to the fields shared by webhook payloads.
+///
+/// Every method returns `None` when the event does not have the field or github
+/// left it out of this delivery.
+pub trait WebhookPayload {
+    /// The action that was performed, ie. `opened`.
+    fn action(&self) -> Option<&str> { None }
+
+    /// The repository the event happened in.
+    fn repository(&self) -> Option<&Repository<'_>> { None }
+
+    /// The user who triggered the event.
+    fn sender(&self) -> Option<&User<'_>> { None }
+
+    /// The organization the event happened in.
+    fn organization(&self) -> Option<&SimpleOrg<'_>> { None }
+
+    /// The Github app installation the event was sent to.
+    fn installation(&self) -> Option<&Installation<'_>> { None }
+}
+
+impl WebhookPayload for GitHubEvent<'_> {
+    fn action(&self) -> Option<&str> {
+        match self {
+            GitHubEvent::Unknown { payload, .. } => payload.get("action")?.as_str(),
+            _ => self.payload()?.action(),
+        }
+    }
+
+    fn repository(&self) -> Option<&Repository<'_>> { self.payload()?.repository() }
+
+    fn sender(&self) -> Option<&User<'_>> { self.payload()?.sender() }
+
+    fn organization(&self) -> Option<&SimpleOrg<'_>> { self.payload()?.organization() }
+
+    fn installation(&self) -> Option<&Installation<'_>> { self.payload()?.installation() }
+}
+
+/// Implements [`WebhookPayload`] for each event from the fields it has.
+///
+/// A field may be `T` or `Option<T>`, both convert into `Option<&T>`.
+macro_rules! webhook_payloads {
+    ( $( $event:ident { $( $field:ident ),* $(,)? } )* ) => {
+        $(
+            impl WebhookPayload for $event<'_> {
+                $( webhook_payloads!(@$field); )*
+            }
+        )*
+    };
+    (@action) => {
+        fn action(&self) -> Option<&str> { Some(self.action.as_ref()) }
+    };
+    (@repository) => {
+        fn repository(&self) -> Option<&Repository<'_>> { (&self.repository).into() }
+    };
+    (@sender) => {
+        fn sender(&self) -> Option<&User<'_>> { (&self.sender).into() }
+    };
+    (@organization) => {
+        fn organization(&self) -> Option<&SimpleOrg<'_>> { (&self.organization).into() }
+    };
+    (@installation) => {
+        fn installation(&self) -> Option<&Installation<'_>> {
+            (&self.installation).into()
+        }
+    };
+}
+
+webhook_payloads! {
+    CheckRunEvent { action, repository, sender, organization, installation }
+    CheckSuiteEvent { action, repository, sender, organization, installation }
+    CodeScanningAlertEvent { action, repository, sender, organization, installation }
+    CommitCommentEvent { action, repository, sender, organization, installation }
+    ContentReferenceEvent { action, repository, sender, organization, installation }
+    CreateEvent { repository, sender, organization, installation }
+    DeleteEvent { repository, sender, organization, installation }
+    DependabotAlertEvent { action, repository, sender, organization, installation }
+    DeployKeyEvent { action, repository, sender, organization, installation }
+    DeploymentEvent { action, repository, sender, organization, installation }
+    DeploymentReviewEvent { action, repository, sender, organization, installation }
+    DeploymentStatusEvent { action, repository, sender, organization, installation }
+    DiscussionEvent { action, repository, sender, organization, installation }
+    DiscussionCommentEvent { action, repository, sender, organization, installation }
+    ForkEvent { repository, sender, organization, installation }
+    GollumEvent { repository, sender, organization, installation }
+    InstallationEvent { action, sender, organization, installation }
+    IssueEvent { action, repository, sender, organization, installation }
+    IssueCommentEvent { action, repository, sender, organization, installation }
+    LabelEvent { action, repository, sender, organization, installation }
+    MemberEvent { action, repository, sender, organization, installation }
+    MembershipEvent { action, sender, organization, installation }
+    MilestoneEvent { action, repository, sender, organization, installation }
+    OrgBlockEvent { action, sender, organization, installation }
+    OrganizationEvent { action, sender, organization, installation }
+    PageBuildEvent { repository, sender, organization, installation }
+    PingEvent { repository, sender, organization }
+    ProjectEvent { action, repository, sender, organization, installation }
+    ProjectCardEvent { action, repository, sender, organization, installation }
+    ProjectColumnEvent { action, repository, sender, organization, installation }
+    PublicEvent { repository, sender, organization, installation }
+    PullRequestEvent { action, repository, sender, organization, installation }
+    PullRequestReviewEvent { action, repository, sender, organization, installation }
+    PullRequestReviewCommentEvent {
+        action, repository, sender, organization, installation
+    }
+    PushEvent { repository, sender, organization, installation }
+    RegistryPackageEvent { action, repository, sender, organization, installation }
+    ReleaseEvent { action, repository, sender, organization, installation }
+    RepositoryEvent { action, repository, sender, organization, installation }
+    RepositoryDispatchEvent { action, repository, sender, organization, installation }
+    RepositoryVulnerabilityAlertEvent {
+        action, repository, sender, organization, installation
+    }
+    SecretScanningAlertEvent { action, repository, sender, organization, installation }
+    StarEvent { action, repository, sender, organization, installation }
+    StatusEvent { repository, sender, organization, installation }
+    TeamEvent { action, repository, sender, organization, installation }
+    TeamAddEvent { repository, sender, organization, installation }
+    WatchEvent { action, repository, sender, organization, installation }
+    WorkflowDispatchEvent { repository, sender, organization, installation }
+    WorkflowJobEvent { action, repository, sender, organization, installation }
+    WorkflowRunEvent { action, repository, sender, organization, installation }
+}
+
+#[test]
+fn payload_fields() {
+    // The fixtures have keys no field captures, `fixture_conformance` covers those
+    let event = GitHubEvent::from_raw_lenient(
+        "star",
+        include_str!("../../../test_json/star.json"),
+    )
+    .unwrap();
+    assert_eq!(event.action(), Some("created"));
+    assert_eq!(event.repository().map(|r| &*r.full_name), Some("DevinR528/cargo-sort"));
+    assert_eq!(event.sender().map(|s| &*s.login), Some("DevinR528"));
+    assert!(event.organization().is_none());
+
+    let event = GitHubEvent::from_raw_lenient(
+        "organization",
+        include_str!("../../../test_json/organization.json"),
+    )
+    .unwrap();
+    assert_eq!(event.action(), Some("member_added"));
+    assert!(event.repository().is_none());
+    assert_eq!(event.organization().map(|o| &*o.login), Some("Octocoders"));
+
+    let event = GitHubEvent::from_raw_lenient(
+        "push",
+        include_str!("../../../test_json/push.json"),
+    )
+    .unwrap();
+    assert!(event.action().is_none());
+    assert_eq!(event.repository().map(|r| &*r.full_name), Some("Codertocat/Hello-World"));
+
+    let event =
+        GitHubEvent::from_raw("sponsorship", r#"{ "action": "created" }"#).unwrap();
+    assert_eq!(event.action(), Some("created"));
+    assert!(event.sender().is_none());
+}
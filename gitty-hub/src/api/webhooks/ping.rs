@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use url::Url;
+
+use crate::{
+    api::{EventKind, Repository, SimpleOrg, Timestamp, UrlMap, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a ping event.
+///
+/// Github sends this once when a new webhook is created.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PingEvent<'a> {
+    /// A random string of github zen.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub zen: Cow<'a, str>,
+
+    /// The Id of the webhook that triggered the ping.
+    pub hook_id: UInt,
+
+    /// The webhook configuration.
+    #[serde(borrow)]
+    pub hook: Hook<'a>,
+
+    /// Detailed information about the repository the hook was created on.
+    ///
+    /// This is not present for organization and app hooks.
+    #[serde(borrow)]
+    pub repository: Option<Repository<'a>>,
+
+    /// Detailed information about the organization the hook was created on.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who created the hook.
+    ///
+    /// This is not present for app hooks.
+    #[serde(borrow)]
+    pub sender: Option<User<'a>>,
+}
+
+/// Information about a webhook.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Hook<'a> {
+    /// What the hook was created on.
+    #[serde(rename = "type")]
+    pub kind: HookType,
+
+    /// Numeric Id of this hook.
+    pub id: UInt,
+
+    /// The name of the hook, this is always `web`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// Whether deliveries are sent when the hook is triggered.
+    pub active: bool,
+
+    /// The events this hook is subscribed to.
+    ///
+    /// A hook subscribed to every event has the single wildcard event `*`.
+    pub events: Vec<EventKind>,
+
+    /// Where and how the payloads are delivered.
+    #[serde(borrow)]
+    pub config: HookConfig<'a>,
+
+    /// The time in UTC when the hook was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the hook was updated.
+    pub updated_at: Timestamp,
+
+    /// A map of all the github api urls.
+    #[serde(flatten, default)]
+    pub all_urls: UrlMap,
+}
+
+impl Hook<'_> {
+    /// Whether the hook is subscribed to the wildcard event `*`, ie. every event.
+    pub fn is_wildcard(&self) -> bool { self.events.iter().any(|ev| ev.as_ref() == "*") }
+
+    /// Whether deliveries of `kind` are sent by this hook.
+    pub fn subscribes_to(&self, kind: &EventKind) -> bool {
+        self.is_wildcard() || self.events.contains(kind)
+    }
+}
+
+/// The configuration of a webhook.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct HookConfig<'a> {
+    /// The media type used to serialize the payloads.
+    pub content_type: ContentType,
+
+    /// Whether the SSL certificate of the host of `url` is verified.
+    ///
+    /// `true` means the certificate is __not__ verified.
+    #[serde(deserialize_with = "insecure_ssl", serialize_with = "ser_insecure_ssl")]
+    pub insecure_ssl: bool,
+
+    /// The url payloads are delivered to.
+    pub url: Url,
+
+    /// The secret, this is always masked by github as `********`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub secret: Option<Cow<'a, str>>,
+}
+
+/// What a webhook was created on.
+#[derive(Clone, Debug, StringEnum)]
+#[non_exhaustive]
+pub enum HookType {
+    /// The hook was created on a repository.
+    Repository,
+
+    /// The hook was created on an organization.
+    Organization,
+
+    /// The hook belongs to a github app.
+    App,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The media type webhook payloads are delivered as.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ContentType {
+    /// `application/json`, the payload is the body of the request.
+    Json,
+
+    /// `application/x-www-form-urlencoded`, the payload is the `payload` form field.
+    Form,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Github sends `insecure_ssl` as the string `"0"` or `"1"`.
+fn insecure_ssl<'de, D>(deser: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag<'a> {
+        Bool(bool),
+        Int(u8),
+        #[serde(borrow)]
+        Str(Cow<'a, str>),
+    }
+
+    match Flag::deserialize(deser)? {
+        Flag::Bool(b) => Ok(b),
+        Flag::Int(0) => Ok(false),
+        Flag::Int(1) => Ok(true),
+        Flag::Str(s) if s == "0" => Ok(false),
+        Flag::Str(s) if s == "1" => Ok(true),
+        _ => Err(de::Error::custom("expected `insecure_ssl` to be \"0\" or \"1\"")),
+    }
+}
+
+fn ser_insecure_ssl<S>(insecure: &bool, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.serialize_str(if *insecure { "1" } else { "0" })
+}
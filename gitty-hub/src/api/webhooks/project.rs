@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Changes, Installation, IssueState, Repository, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a project event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ProjectEvent<'a> {
+    /// The action that was performed.
+    pub action: ProjectAction,
+
+    /// The project that was changed.
+    #[serde(borrow)]
+    pub project: Project<'a>,
+
+    /// Changes are only present if the action was edited.
+    #[serde(borrow)]
+    pub changes: Option<Changes<'a>>,
+
+    /// Detailed information about the repository the project belongs to.
+    ///
+    /// This is not present for organization and user projects.
+    #[serde(borrow)]
+    pub repository: Option<Repository<'a>>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the project belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the project.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a project event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProjectAction {
+    /// A project was created.
+    Created,
+
+    /// The name or body of a project was edited.
+    Edited,
+
+    /// A project was closed.
+    Closed,
+
+    /// A project was reopened.
+    Reopened,
+
+    /// A project was deleted.
+    Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a project board.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Project<'a> {
+    /// Numeric Id of this project.
+    pub id: UInt,
+
+    /// String identifier of the project.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The number of this project.
+    pub number: UInt,
+
+    /// The name of this project.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The body of this project.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub body: Option<Cow<'a, str>>,
+
+    /// The state of this project.
+    pub state: IssueState,
+
+    /// The user who created the project.
+    #[serde(borrow)]
+    pub creator: User<'a>,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The github api url of this project.
+    pub url: Url,
+
+    /// The github api url of the columns of this project.
+    pub columns_url: Url,
+
+    /// The github api url of the repository, organization or user that owns this
+    /// project.
+    pub owner_url: Url,
+
+    /// The time in UTC when the project was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the project was updated.
+    pub updated_at: Timestamp,
+}
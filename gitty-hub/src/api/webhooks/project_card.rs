@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Body, Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a project card event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ProjectCardEvent<'a> {
+    /// The action that was performed.
+    pub action: ProjectCardAction,
+
+    /// The card that was changed.
+    #[serde(borrow)]
+    pub project_card: ProjectCard<'a>,
+
+    /// Changes are only present if the action was edited or moved.
+    #[serde(borrow)]
+    pub changes: Option<ProjectCardChanges<'a>>,
+
+    /// Numeric Id of the card this card now follows.
+    ///
+    /// This is only present if the action was moved, `None` means the card is first
+    /// in its column.
+    pub after_id: Option<UInt>,
+
+    /// Detailed information about the repository the project belongs to.
+    ///
+    /// This is not present for organization and user projects.
+    #[serde(borrow)]
+    pub repository: Option<Repository<'a>>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the project belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the card.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a project card event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProjectCardAction {
+    /// A card was added to a project.
+    Created,
+
+    /// The note of a card was edited.
+    Edited,
+
+    /// A note card was converted to an issue.
+    Converted,
+
+    /// A card was moved to another column or position.
+    Moved,
+
+    /// A card was deleted.
+    Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a card on a project board.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ProjectCard<'a> {
+    /// Numeric Id of this card.
+    pub id: UInt,
+
+    /// String identifier of the card.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The note of this card, this is `None` for issue and pull request cards.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub note: Option<Cow<'a, str>>,
+
+    /// Is this card archived.
+    #[serde(default)]
+    pub archived: bool,
+
+    /// The user who created the card.
+    #[serde(borrow)]
+    pub creator: Option<User<'a>>,
+
+    /// Numeric Id of the column this card is in.
+    pub column_id: UInt,
+
+    /// The github api url of the column this card is in.
+    pub column_url: Url,
+
+    /// The github api url of the issue or pull request this card refers to.
+    pub content_url: Option<Url>,
+
+    /// The github api url of the project this card is on.
+    pub project_url: Url,
+
+    /// The github api url of this card.
+    pub url: Url,
+
+    /// The time in UTC when the card was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the card was updated.
+    pub updated_at: Timestamp,
+}
+
+/// The changes made to a project card.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ProjectCardChanges<'a> {
+    /// The previous note of the card.
+    #[serde(borrow)]
+    pub note: Option<Body<'a>>,
+
+    /// The column the card was moved out of.
+    pub column_id: Option<ColumnChange>,
+}
+
+/// The previous column of a moved project card.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ColumnChange {
+    /// Numeric Id of the previous column.
+    pub from: UInt,
+}
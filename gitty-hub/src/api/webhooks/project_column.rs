@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Changes, Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::deserialize_cow_str,
+};
+
+/// The payload of a project column event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ProjectColumnEvent<'a> {
+    /// The action that was performed.
+    pub action: ProjectColumnAction,
+
+    /// The column that was changed.
+    #[serde(borrow)]
+    pub project_column: ProjectColumn<'a>,
+
+    /// Changes are only present if the action was edited.
+    #[serde(borrow)]
+    pub changes: Option<Changes<'a>>,
+
+    /// Numeric Id of the column this column now follows.
+    ///
+    /// This is only present if the action was moved, `None` means the column is
+    /// first.
+    pub after_id: Option<UInt>,
+
+    /// Detailed information about the repository the project belongs to.
+    ///
+    /// This is not present for organization and user projects.
+    #[serde(borrow)]
+    pub repository: Option<Repository<'a>>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the project belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the column.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a project column event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProjectColumnAction {
+    /// A column was added to a project.
+    Created,
+
+    /// The name of a column was edited.
+    Edited,
+
+    /// A column was moved to another position.
+    Moved,
+
+    /// A column was deleted.
+    Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a column on a project board.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ProjectColumn<'a> {
+    /// Numeric Id of this column.
+    pub id: UInt,
+
+    /// String identifier of the column.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The name of this column.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The github api url of this column.
+    pub url: Url,
+
+    /// The github api url of the project this column is on.
+    pub project_url: Url,
+
+    /// The github api url of the cards in this column.
+    pub cards_url: Url,
+
+    /// The time in UTC when the column was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the column was updated.
+    pub updated_at: Timestamp,
+}
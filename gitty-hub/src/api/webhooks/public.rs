@@ -0,0 +1,28 @@
+use github_derive::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Installation, Repository, SimpleOrg, User};
+
+/// The payload of a public event.
+///
+/// This is sent when a private repository is made public.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PublicEvent<'a> {
+    /// Detailed information about the repository that was made public.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who made the repo public.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
@@ -1,11 +1,11 @@
-use github_derive::StringEnum;
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::api::{Changes, Installation, Org, PullRequest, Repository, User};
+use crate::api::{Body, Diff, Installation, PullRequest, Repository, SimpleOrg, User};
 
 /// The payload of a pull request event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct PullRequestEvent<'a> {
     /// The action that was performed.
     pub action: PullRequestAction,
@@ -17,7 +17,7 @@ pub struct PullRequestEvent<'a> {
     ///
     /// Only present for [`PullAction::Edited`].
     #[serde(borrow)]
-    pub changes: Option<Changes<'a>>,
+    pub changes: Option<PullRequestChanges<'a>>,
 
     /// Information about the pull request.
     #[serde(borrow)]
@@ -36,13 +36,36 @@ pub struct PullRequestEvent<'a> {
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who stared the repo.
     #[serde(borrow)]
     pub sender: User<'a>,
 }
 
+impl PullRequestEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default()
+            .field("title", changes.title.as_ref(), &*self.pull_request.title)
+            .field("body", changes.body.as_ref(), &*self.pull_request.body)
+            .field(
+                "base",
+                changes.base.as_ref().and_then(|base| base.ref_.as_ref()),
+                &*self.pull_request.base.ref_,
+            )
+            .field(
+                "base sha",
+                changes.base.as_ref().and_then(|base| base.sha.as_ref()),
+                &*self.pull_request.base.sha,
+            )
+    }
+}
+
 /// The actions that can be taken for a pull request.
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "snake_case")]
@@ -100,4 +123,35 @@ pub enum PullRequestAction {
 
     /// Pull request has been unlocked.
     Unlocked,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// The changes made to a pull request.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PullRequestChanges<'a> {
+    /// The previous title of the pull request.
+    #[serde(borrow)]
+    pub title: Option<Body<'a>>,
+
+    /// The previous body of the pull request.
+    #[serde(borrow)]
+    pub body: Option<Body<'a>>,
+
+    /// The previous base branch of the pull request.
+    #[serde(borrow)]
+    pub base: Option<BaseChanges<'a>>,
+}
+
+/// The previous base branch of a pull request.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct BaseChanges<'a> {
+    /// The previous name of the base branch.
+    #[serde(rename = "ref", borrow)]
+    pub ref_: Option<Body<'a>>,
+
+    /// The previous SHA the pull request was based on.
+    #[serde(borrow)]
+    pub sha: Option<Body<'a>>,
 }
@@ -1,15 +1,20 @@
-use github_derive::StringEnum;
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{
-    AuthorAssociation, Changes, Dt, Installation, Links, Org, PullRequest, Repository,
-    User,
+use crate::{
+    api::{
+        AuthorAssociation, CommentChanges, Diff, Installation, Links, PullRequest,
+        Repository, SimpleOrg, Timestamp, User,
+    },
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
 };
 
 /// The payload of a pull request review event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct PullRequestReviewEvent<'a> {
     /// The action that was performed.
     pub action: PullRequestReviewAction,
@@ -18,7 +23,7 @@ pub struct PullRequestReviewEvent<'a> {
     ///
     /// Only present for [`PullAction::Edited`].
     #[serde(borrow)]
-    pub changes: Option<Changes<'a>>,
+    pub changes: Option<CommentChanges<'a>>,
 
     /// Information about the pull request.
     #[serde(borrow)]
@@ -40,13 +45,28 @@ pub struct PullRequestReviewEvent<'a> {
 
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who stared the repo.
     #[serde(borrow)]
     pub sender: User<'a>,
 }
 
+impl PullRequestReviewEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default().field(
+            "body",
+            changes.body.as_ref(),
+            self.review.body.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
 /// The actions that can be taken for a pull request review.
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "lowercase")]
@@ -65,30 +85,39 @@ pub enum PullRequestReviewAction {
 
     /// A review has been dismissed.
     Dismissed,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// The review of a pull request.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct PullRequestReview<'a> {
     /// Numeric Id of this review.
     pub id: UInt,
 
     /// String identifier of the review.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// Information about the owner of this review.
     #[serde(borrow)]
     pub user: User<'a>,
 
+    /// The body of the review.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub body: Option<Cow<'a, str>>,
+
     /// The public web page url.
     pub html_url: Url,
 
     /// Time in UTC this pull request was submitted.
-    pub submitted_at: Dt,
+    pub submitted_at: Timestamp,
 
     /// The state of the pull request review.
     // TODO: make this an enum
-    pub state: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub state: Cow<'a, str>,
 
     /// The Github api url for the related pull request.
     pub pull_request_url: Url,
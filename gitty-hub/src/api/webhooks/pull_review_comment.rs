@@ -1,17 +1,20 @@
 use std::{borrow::Cow, path::Path};
 
-use github_derive::StringEnum;
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{
-    datetime, AuthorAssociation, Changes, Dt, Installation, Links, Org, PullRequest,
-    Repository, User,
+use crate::{
+    api::{
+        AuthorAssociation, CommentChanges, Diff, Installation, Links, PullRequest,
+        Repository, SimpleOrg, Timestamp, User,
+    },
+    utils::deserialize_cow_str,
 };
 
 /// The payload of a pull request review comment event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct PullRequestReviewCommentEvent<'a> {
     /// The action that was performed.
     pub action: PullRequestReviewCommentAction,
@@ -20,7 +23,7 @@ pub struct PullRequestReviewCommentEvent<'a> {
     ///
     /// Only present for [`PullAction::Edited`].
     #[serde(borrow)]
-    pub changes: Option<Changes<'a>>,
+    pub changes: Option<CommentChanges<'a>>,
 
     /// The pull request review comment.
     #[serde(borrow)]
@@ -42,13 +45,24 @@ pub struct PullRequestReviewCommentEvent<'a> {
 
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who stared the repo.
     #[serde(borrow)]
     pub sender: User<'a>,
 }
 
+impl PullRequestReviewCommentEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default().field("body", changes.body.as_ref(), &*self.comment.body)
+    }
+}
+
 /// The actions that can be taken for a pull request review.
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "lowercase")]
@@ -62,10 +76,13 @@ pub enum PullRequestReviewCommentAction {
 
     /// A review has been dismissed.
     Dismissed,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// The review of a pull request.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct PullRequestReviewComment<'a> {
     /// Numeric Id of this review comment.
     pub id: UInt,
@@ -74,7 +91,8 @@ pub struct PullRequestReviewComment<'a> {
     pub pull_request_review_id: UInt,
 
     /// String identifier of the review.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The public web page url.
     pub html_url: Url,
@@ -83,7 +101,7 @@ pub struct PullRequestReviewComment<'a> {
     pub url: Url,
 
     /// The diff of the line tat the comment refers to.
-    #[serde(borrow)]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
     pub diff_hunk: Cow<'a, str>,
 
     /// The user who commented on this commit.
@@ -97,24 +115,25 @@ pub struct PullRequestReviewComment<'a> {
     pub original_position: UInt,
 
     /// The relative file path.
-    pub path: Option<&'a Path>,
+    pub path: Option<Cow<'a, Path>>,
 
     /// The SHA of this commit.
-    pub commit_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub commit_id: Cow<'a, str>,
 
     /// The SHA of the original commit.
-    pub original_commit_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub original_commit_id: Cow<'a, str>,
 
     /// Time in UTC this commit was created.
-    #[serde(deserialize_with = "datetime")]
-    pub created_at: Dt,
+    pub created_at: Timestamp,
 
     /// Time in UTC this commit was last updated.
-    #[serde(deserialize_with = "datetime")]
-    pub updated_at: Dt,
+    pub updated_at: Timestamp,
 
     /// The body of the message.
-    pub body: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub body: Cow<'a, str>,
 
     /// The Github api url for the related pull request.
     pub pull_request_url: Url,
@@ -151,7 +170,7 @@ pub struct PullRequestReviewComment<'a> {
 }
 
 /// The side a multi-line comment starts on.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum CommentSide {
     /// A multi-line comment starts on the left side.
@@ -1,19 +1,29 @@
-use serde::Deserialize;
+use std::borrow::Cow;
 
-use crate::api::{Committer, Installation, Org, Repository, User};
+use github_derive::IntoOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
+
+use crate::{
+    api::{Committer, Installation, Repository, SimpleOrg, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt, deserialize_cow_str_vec},
+};
 
 /// The payload of a push event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct PushEvent<'a> {
     /// The name of this ref.
     #[serde(rename = "ref")]
-    pub ref_: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
 
     /// The commit SHA before the push.
-    pub before: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub before: Cow<'a, str>,
 
     /// The commit SHA after the push.
-    pub after: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub after: Cow<'a, str>,
 
     /// Did this push create a new commit.
     pub created: bool,
@@ -25,10 +35,12 @@ pub struct PushEvent<'a> {
     pub forced: bool,
 
     /// The SHA of the base.
-    pub base_ref: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub base_ref: Option<Cow<'a, str>>,
 
     /// Compare if changes were made.
-    pub compare: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub compare: Cow<'a, str>,
 
     /// The author of the last commit for this push.
     #[serde(borrow)]
@@ -55,7 +67,7 @@ pub struct PushEvent<'a> {
     /// Detailed information about the organization the repository that was pushed
     /// to belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who pushed to the repository.
     #[serde(borrow)]
@@ -63,23 +75,26 @@ pub struct PushEvent<'a> {
 }
 
 /// Information about a specific commit.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct PushCommit<'a> {
     /// The sha of this commit.
-    pub id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub id: Cow<'a, str>,
 
     /// The identifier of this commit.
-    pub tree_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub tree_id: Cow<'a, str>,
 
     /// Is this commit distinct from others.
     pub distinct: bool,
 
     /// The commit message.
-    #[serde(borrow)]
-    pub message: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub message: Cow<'a, str>,
 
     /// The api url of the commit referenced.
-    pub url: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub url: Cow<'a, str>,
 
     /// The author of this commit.
     #[serde(borrow)]
@@ -90,14 +105,57 @@ pub struct PushCommit<'a> {
     pub committer: Committer<'a>,
 
     /// The files that were added.
-    #[serde(default)]
-    pub added: Vec<&'a str>,
+    #[serde(default, borrow)]
+    pub added: FileList<'a>,
 
     /// The files that were removed.
-    #[serde(default)]
-    pub removed: Vec<&'a str>,
+    #[serde(default, borrow)]
+    pub removed: FileList<'a>,
 
     /// The files that were modified.
-    #[serde(default)]
-    pub modified: Vec<&'a str>,
+    #[serde(default, borrow)]
+    pub modified: FileList<'a>,
+}
+
+/// The paths of the files a commit changed, parsed when they are read.
+///
+/// A push to a big repository can list thousands of paths that are rarely looked at, so
+/// the JSON array is kept as github sent it. Parsing the payload only checks that it is
+/// valid JSON, [`FileList::paths`] fails if it is not a list of strings.
+#[derive(Clone, Debug, Serialize, IntoOwned)]
+#[serde(transparent)]
+pub struct FileList<'a> {
+    raw: Cow<'a, RawValue>,
+}
+
+impl FileList<'_> {
+    /// Parses the paths, paths without escapes borrow from the payload.
+    pub fn paths(&self) -> serde_json::Result<Vec<Cow<'_, str>>> {
+        deserialize_cow_str_vec(&mut serde_json::Deserializer::from_str(self.raw.get()))
+    }
+
+    /// Returns `true` if no files are listed.
+    pub fn is_empty(&self) -> bool {
+        matches!(self.raw.get().strip_prefix('['), Some(rest) if rest.trim_start().starts_with(']'))
+    }
+
+    /// The JSON array github sent.
+    pub fn as_json(&self) -> &str { self.raw.get() }
+}
+
+impl Default for FileList<'_> {
+    fn default() -> Self {
+        Self { raw: Cow::Borrowed(serde_json::from_str("[]").unwrap()) }
+    }
+}
+
+/// Only deserializers that borrow from their input, like `serde_json::from_str`, can
+/// keep the array without copying it.
+impl<'de: 'a, 'a> Deserialize<'de> for FileList<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <&RawValue>::deserialize(deserializer).map(|raw| Self { raw: Cow::Borrowed(raw) })
+    }
 }
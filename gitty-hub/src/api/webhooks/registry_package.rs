@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a registry package event, this is sent when a package is published
+/// to github packages.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct RegistryPackageEvent<'a> {
+    /// The action that was performed.
+    pub action: RegistryPackageAction,
+
+    /// The package that was published or updated.
+    #[serde(borrow)]
+    pub registry_package: RegistryPackage<'a>,
+
+    /// Detailed information about the repository the package belongs to.
+    ///
+    /// This is not present for packages that are not linked to a repository.
+    #[serde(borrow)]
+    pub repository: Option<Repository<'a>>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the package belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who published the package.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a registry package event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RegistryPackageAction {
+    /// A package version was published.
+    Published,
+
+    /// A package version was updated.
+    Updated,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a package in github packages.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct RegistryPackage<'a> {
+    /// Numeric Id of this package.
+    pub id: UInt,
+
+    /// The name of this package.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The namespace of this package, this is usually the owner.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub namespace: Option<Cow<'a, str>>,
+
+    /// The description of this package.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// The package ecosystem, ie. `CONTAINER` or `npm`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ecosystem: Cow<'a, str>,
+
+    /// The kind of package, ie. `CONTAINER` or `npm`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub package_type: Cow<'a, str>,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The user or organization that owns the package.
+    #[serde(borrow)]
+    pub owner: User<'a>,
+
+    /// The version that was published or updated.
+    #[serde(borrow)]
+    pub package_version: Option<PackageVersion<'a>>,
+
+    /// The registry the package was published to.
+    #[serde(borrow)]
+    pub registry: Option<Registry<'a>>,
+
+    /// The time in UTC when the package was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the package was updated.
+    pub updated_at: Option<Timestamp>,
+}
+
+/// A published version of a package.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct PackageVersion<'a> {
+    /// Numeric Id of this version.
+    pub id: UInt,
+
+    /// The version, ie. `1.2.0` or a container tag.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub version: Cow<'a, str>,
+
+    /// The name of the package this version belongs to.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// A short description of this version.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub summary: Option<Cow<'a, str>>,
+
+    /// The branch or commit this version was built from.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub target_commitish: Option<Cow<'a, str>>,
+
+    /// The SHA of the commit this version was built from.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub target_oid: Option<Cow<'a, str>>,
+
+    /// The command to install this version, ie. `docker pull ghcr.io/..`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub installation_command: Option<Cow<'a, str>>,
+
+    /// The user who published this version.
+    #[serde(borrow)]
+    pub author: Option<User<'a>>,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The time in UTC when the version was created.
+    pub created_at: Option<Timestamp>,
+
+    /// The time in UTC when the version was updated.
+    pub updated_at: Option<Timestamp>,
+}
+
+/// A package registry.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct Registry<'a> {
+    /// The name of the registry, ie. `GitHub CONTAINER registry`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The kind of registry, ie. `CONTAINER`.
+    #[serde(rename = "type")]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub kind: Cow<'a, str>,
+
+    /// The url of the registry, ie. `https://ghcr.io/octocat`.
+    pub url: Url,
+
+    /// The company that runs the registry.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub vendor: Cow<'a, str>,
+
+    /// The web page about the registry.
+    pub about_url: Option<Url>,
+}
@@ -1,14 +1,20 @@
-use github_derive::StringEnum;
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{
-    datetime_opt, default_null, Changes, Dt, Installation, Org, Repository, UrlMap, User,
+use crate::{
+    api::{
+        default_null, Body, Diff, Installation, Repository, SimpleOrg, Timestamp, UrlMap,
+        User,
+    },
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
 };
 
 /// The payload of a release event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct ReleaseEvent<'a> {
     /// One of `created` or `deleted`.
     pub action: ReleaseAction,
@@ -19,7 +25,7 @@ pub struct ReleaseEvent<'a> {
 
     /// Changes are only present if the action was edited.
     #[serde(borrow)]
-    pub changes: Option<Changes<'a>>,
+    pub changes: Option<ReleaseChanges<'a>>,
 
     /// Detailed information about the repository that was stared.
     #[serde(borrow)]
@@ -34,13 +40,35 @@ pub struct ReleaseEvent<'a> {
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who stared the repo.
     #[serde(borrow)]
     pub sender: User<'a>,
 }
 
+impl ReleaseEvent<'_> {
+    /// What the edit changed, this is empty unless the action was edited.
+    pub fn diff(&self) -> Diff<'_> {
+        let changes = match &self.changes {
+            Some(changes) => changes,
+            None => return Diff::default(),
+        };
+        Diff::default()
+            .field(
+                "name",
+                changes.name.as_ref(),
+                self.release.name.as_deref().unwrap_or_default(),
+            )
+            .field("tag", changes.tag_name.as_ref(), &*self.release.tag_name)
+            .field(
+                "body",
+                changes.body.as_ref(),
+                self.release.body.as_deref().unwrap_or_default(),
+            )
+    }
+}
+
 /// The specific actions that a release event has.
 #[derive(Clone, Debug, StringEnum)]
 #[github_enum(rename_all = "lowercase")]
@@ -68,10 +96,13 @@ pub enum ReleaseAction {
     /// A release or draft of a release is published, or a pre-release is changed to
     /// a release.
     Release,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
 
 /// Information about a release.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct Release<'a> {
     /// The Github API url.
     pub url: Url,
@@ -86,16 +117,20 @@ pub struct Release<'a> {
     pub id: UInt,
 
     /// String identifier of the release.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The name of this release tag.
-    pub tag_name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub tag_name: Cow<'a, str>,
 
     /// The branch this release came from.
-    pub target_commitish: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub target_commitish: Cow<'a, str>,
 
     /// The name of this release.
-    pub name: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub name: Option<Cow<'a, str>>,
 
     /// Is this release a draft.
     #[serde(default, deserialize_with = "default_null")]
@@ -106,12 +141,10 @@ pub struct Release<'a> {
     pub author: User<'a>,
 
     /// The time in UTC when the release was created.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub created_at: Option<Dt>,
+    pub created_at: Option<Timestamp>,
 
     /// The time in UTC when the release was published.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub published_at: Option<Dt>,
+    pub published_at: Option<Timestamp>,
 
     /// Is this release a pre-release.
     #[serde(default, deserialize_with = "default_null")]
@@ -122,7 +155,8 @@ pub struct Release<'a> {
     pub assets: Vec<Assets<'a>>,
 
     /// The message attached to this release.
-    pub body: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub body: Option<Cow<'a, str>>,
 
     /// A map of all the github api urls.
     #[serde(flatten, default)]
@@ -130,7 +164,7 @@ pub struct Release<'a> {
 }
 
 /// Assets including in a release.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct Assets<'a> {
     /// The Github API url.
     pub url: Url,
@@ -142,16 +176,20 @@ pub struct Assets<'a> {
     pub id: UInt,
 
     /// String identifier of the asset.
-    pub node_id: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
 
     /// The label on this asset.
-    pub label: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub label: Cow<'a, str>,
 
     /// The type of the asset.
-    pub content_type: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub content_type: Cow<'a, str>,
 
     /// The file name of this asset.
-    pub name: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub name: Option<Cow<'a, str>>,
 
     /// The size of this asset.
     pub size: UInt,
@@ -160,14 +198,28 @@ pub struct Assets<'a> {
     pub download_count: UInt,
 
     /// The time in UTC when the release was created.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub created_at: Option<Dt>,
+    pub created_at: Option<Timestamp>,
 
     /// The time in UTC when the release was published.
-    #[serde(default, deserialize_with = "datetime_opt")]
-    pub uploaded_at: Option<Dt>,
+    pub uploaded_at: Option<Timestamp>,
 
     /// The name of this release.
     #[serde(borrow)]
     pub uploader: Option<User<'a>>,
 }
+
+/// The changes made to a release.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct ReleaseChanges<'a> {
+    /// The previous name of the release.
+    #[serde(borrow)]
+    pub name: Option<Body<'a>>,
+
+    /// The previous tag of the release.
+    #[serde(borrow)]
+    pub tag_name: Option<Body<'a>>,
+
+    /// The previous body of the release.
+    #[serde(borrow)]
+    pub body: Option<Body<'a>>,
+}
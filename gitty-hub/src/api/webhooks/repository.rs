@@ -0,0 +1,69 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Changes, Installation, Repository, SimpleOrg, User};
+
+/// The payload of a repository event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct RepositoryEvent<'a> {
+    /// The action that was performed.
+    pub action: RepositoryAction,
+
+    /// Changes are only present if the action was edited or renamed.
+    #[serde(borrow)]
+    pub changes: Option<Changes<'a>>,
+
+    /// Detailed information about the repository that was changed.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who changed the repo.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a repository event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RepositoryAction {
+    /// A repository was created.
+    Created,
+
+    /// A repository was deleted.
+    Deleted,
+
+    /// A repository was archived.
+    Archived,
+
+    /// A repository was unarchived.
+    Unarchived,
+
+    /// The description, homepage, default branch or topics of a repository changed.
+    Edited,
+
+    /// A repository was renamed, the old name is in `changes.repository.name`.
+    Renamed,
+
+    /// Ownership of a repository was transferred to another user or organization.
+    Transferred,
+
+    /// A repository was made public.
+    Publicized,
+
+    /// A repository was made private.
+    Privatized,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
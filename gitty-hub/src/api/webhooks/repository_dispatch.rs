@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use github_derive::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, User},
+    utils::deserialize_cow_str,
+};
+
+/// The payload of a repository dispatch event, this is sent when the
+/// `/repos/{owner}/{repo}/dispatches` endpoint is called.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct RepositoryDispatchEvent<'a> {
+    /// The `event_type` that was passed to the dispatches endpoint.
+    ///
+    /// This is chosen by the caller so it is not an enum like other actions.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub action: Cow<'a, str>,
+
+    /// The default branch of the repository.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub branch: Cow<'a, str>,
+
+    /// The `client_payload` that was passed to the dispatches endpoint.
+    pub client_payload: Option<serde_json::Value>,
+
+    /// Detailed information about the repository the event was dispatched to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who dispatched the event.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
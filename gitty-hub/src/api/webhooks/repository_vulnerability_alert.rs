@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{AlertSeverity, Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a repository vulnerability alert event.
+///
+/// This is the older form of the dependabot alert event, github still sends it to
+/// hooks subscribed to it.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct RepositoryVulnerabilityAlertEvent<'a> {
+    /// The action that was performed.
+    pub action: RepositoryVulnerabilityAlertAction,
+
+    /// The vulnerability alert.
+    #[serde(borrow)]
+    pub alert: VulnerabilityAlert<'a>,
+
+    /// Detailed information about the repository the alert belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the event.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a repository vulnerability alert event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RepositoryVulnerabilityAlertAction {
+    /// A vulnerable dependency was found.
+    Create,
+
+    /// Someone dismissed a vulnerability alert.
+    Dismiss,
+
+    /// Someone reopened a vulnerability alert.
+    Reopen,
+
+    /// The vulnerable dependency was updated or removed.
+    Resolve,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a vulnerable dependency of a repository.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct VulnerabilityAlert<'a> {
+    /// Numeric Id of this alert.
+    pub id: UInt,
+
+    /// The number of this alert within the repository.
+    pub number: Option<UInt>,
+
+    /// String identifier of the alert.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub node_id: Option<Cow<'a, str>>,
+
+    /// The state of this alert.
+    pub state: VulnerabilityAlertState,
+
+    /// The severity of the vulnerability.
+    pub severity: AlertSeverity,
+
+    /// The name of the vulnerable package.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub affected_package_name: Cow<'a, str>,
+
+    /// The vulnerable versions, ie. `< 0.2.1`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub affected_range: Cow<'a, str>,
+
+    /// The first version that is not vulnerable.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub fixed_in: Option<Cow<'a, str>>,
+
+    /// The GHSA identifier of the advisory, ie. `GHSA-rf4j-j272-fj86`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ghsa_id: Cow<'a, str>,
+
+    /// The identifier of the advisory in the database it came from.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub external_identifier: Cow<'a, str>,
+
+    /// A link to the advisory in the database it came from.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub external_reference: Cow<'a, str>,
+
+    /// The user who dismissed the alert.
+    #[serde(borrow)]
+    pub dismisser: Option<User<'a>>,
+
+    /// The reason the alert was dismissed.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub dismiss_reason: Option<Cow<'a, str>>,
+
+    /// The time in UTC when the alert was dismissed.
+    pub dismissed_at: Option<Timestamp>,
+
+    /// The time in UTC when the alert was fixed.
+    pub fixed_at: Option<Timestamp>,
+
+    /// The time in UTC when the alert was created.
+    pub created_at: Timestamp,
+}
+
+/// The state of a repository vulnerability alert.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum VulnerabilityAlertState {
+    /// The alert is open.
+    Open,
+
+    /// The alert was dismissed by a user.
+    Dismissed,
+
+    /// The vulnerable dependency was updated or removed.
+    Fixed,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
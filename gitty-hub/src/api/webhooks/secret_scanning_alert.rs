@@ -0,0 +1,196 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, Timestamp, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a secret scanning alert event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct SecretScanningAlertEvent<'a> {
+    /// The action that was performed.
+    pub action: SecretScanningAlertAction,
+
+    /// The secret scanning alert.
+    #[serde(borrow)]
+    pub alert: SecretScanningAlert<'a>,
+
+    /// Detailed information about the repository the secret was found in.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the event.
+    ///
+    /// This is not present when the alert was created by a scan.
+    #[serde(borrow)]
+    pub sender: Option<User<'a>>,
+}
+
+/// The specific actions that a secret scanning alert event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SecretScanningAlertAction {
+    /// A secret was found in a repository.
+    Created,
+
+    /// A secret scanning alert was reopened.
+    Reopened,
+
+    /// A secret scanning alert was closed.
+    Resolved,
+
+    /// The secret of an alert was revoked by the provider.
+    Revoked,
+
+    /// The validity of the secret was checked with the provider.
+    Validated,
+
+    /// The secret was found in a public repository.
+    PubliclyLeaked,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a secret scanning alert.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct SecretScanningAlert<'a> {
+    /// The number of this alert within the repository.
+    pub number: UInt,
+
+    /// The kind of secret that was found, ie. `adafruit_io_key`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub secret_type: Cow<'a, str>,
+
+    /// The human readable kind of secret, ie. `Adafruit IO Key`.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub secret_type_display_name: Option<Cow<'a, str>>,
+
+    /// The state of this alert.
+    ///
+    /// Older deliveries leave this out, [`SecretScanningAlert::is_open`] falls back
+    /// to `resolution`.
+    pub state: Option<SecretScanningAlertState>,
+
+    /// Why the alert was closed, this is `None` while the alert is open.
+    pub resolution: Option<SecretScanningResolution>,
+
+    /// The comment left when the alert was closed.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub resolution_comment: Option<Cow<'a, str>>,
+
+    /// The user who closed the alert.
+    #[serde(borrow)]
+    pub resolved_by: Option<User<'a>>,
+
+    /// The time in UTC when the alert was closed.
+    pub resolved_at: Option<Timestamp>,
+
+    /// Whether the secret is still usable, according to the provider.
+    pub validity: Option<SecretValidity>,
+
+    /// Whether someone pushed the secret past push protection.
+    pub push_protection_bypassed: Option<bool>,
+
+    /// The github api url of this alert.
+    pub url: Url,
+
+    /// The public web page url.
+    pub html_url: Url,
+
+    /// The github api url of the files and commits the secret was found in.
+    pub locations_url: Option<Url>,
+
+    /// The time in UTC when the alert was created.
+    pub created_at: Timestamp,
+
+    /// The time in UTC when the alert was updated.
+    pub updated_at: Option<Timestamp>,
+}
+
+impl SecretScanningAlert<'_> {
+    /// Whether this alert still needs attention.
+    pub fn is_open(&self) -> bool {
+        match &self.state {
+            Some(SecretScanningAlertState::Open) => true,
+            Some(SecretScanningAlertState::Resolved) => false,
+            _ => self.resolution.is_none(),
+        }
+    }
+}
+
+/// The state of a secret scanning alert.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum SecretScanningAlertState {
+    /// The alert is open.
+    Open,
+
+    /// The alert was closed.
+    Resolved,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Why a secret scanning alert was closed.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SecretScanningResolution {
+    /// The secret is not a real secret.
+    FalsePositive,
+
+    /// The secret will not be revoked.
+    WontFix,
+
+    /// The secret was revoked.
+    Revoked,
+
+    /// The secret is only used in tests.
+    UsedInTests,
+
+    /// The custom pattern that found the secret was deleted.
+    PatternDeleted,
+
+    /// The custom pattern that found the secret was changed.
+    PatternEdited,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Whether a secret can still be used.
+#[derive(Clone, Debug, PartialEq, Eq, StringEnum)]
+#[github_enum(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum SecretValidity {
+    /// The secret is still usable.
+    Active,
+
+    /// The secret can no longer be used.
+    Inactive,
+
+    /// The provider could not be asked.
+    Unknown,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
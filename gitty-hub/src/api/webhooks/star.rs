@@ -1,16 +1,16 @@
-use github_derive::StringEnum;
-use serde::Deserialize;
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
 
-use crate::api::{Dt, Installation, Org, Repository, User};
+use crate::api::{Installation, Repository, SimpleOrg, Timestamp, User};
 
 /// The payload of a star event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct StarEvent<'a> {
     /// One of `created` or `deleted`.
     pub action: StarAction,
 
     /// The time in UTC when this repo was stared.
-    pub starred_at: Dt,
+    pub starred_at: Timestamp,
 
     /// Detailed information about the repository that was stared.
     #[serde(borrow)]
@@ -25,7 +25,7 @@ pub struct StarEvent<'a> {
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who stared the repo.
     #[serde(borrow)]
@@ -42,4 +42,7 @@ pub enum StarAction {
 
     /// The star was deleted from this repository.
     Deleted,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
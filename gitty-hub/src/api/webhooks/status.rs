@@ -1,18 +1,24 @@
-use github_derive::StringEnum;
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
 use js_int::UInt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::api::{Branch, Commit, Installation, Org, Repository, User};
+use crate::{
+    api::{Branch, Commit, Installation, Repository, SimpleOrg, User},
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
 
 /// The payload of a status event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct StatusEvent<'a> {
     /// The unique identifier of the status.
     pub id: UInt,
 
     /// The commit sha
-    pub sha: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub sha: Cow<'a, str>,
 
     /// One of `pending`, `success`, `failure`, or `error`.
     pub state: StatusState,
@@ -22,7 +28,8 @@ pub struct StatusEvent<'a> {
     pub commit: Commit<'a>,
 
     /// The optional human-readable description added to the status.
-    pub description: Option<&'a str>,
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub description: Option<Cow<'a, str>>,
 
     /// The optional link added to the status.
     pub target_url: Option<Url>,
@@ -43,7 +50,7 @@ pub struct StatusEvent<'a> {
     /// Detailed information about the organization the repo that was stared
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Detailed information about the user who stared the repo.
     #[serde(borrow)]
@@ -66,4 +73,7 @@ pub enum StatusState {
 
     /// Status has error-ed.
     Error,
+
+    #[doc(hidden)]
+    _Custom(String),
 }
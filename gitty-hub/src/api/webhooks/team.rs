@@ -0,0 +1,63 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Changes, Installation, Repository, SimpleOrg, Team, User};
+
+/// The payload of a team event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct TeamEvent<'a> {
+    /// The action that was performed.
+    pub action: TeamAction,
+
+    /// The team that was changed.
+    #[serde(borrow)]
+    pub team: Team<'a>,
+
+    /// Changes are only present if the action was edited.
+    #[serde(borrow)]
+    pub changes: Option<Changes<'a>>,
+
+    /// The repository the team was added to or removed from.
+    ///
+    /// This is only present for the repository actions.
+    #[serde(borrow)]
+    pub repository: Option<Repository<'a>>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the team belongs to.
+    #[serde(borrow)]
+    pub organization: SimpleOrg<'a>,
+
+    /// Detailed information about the user who changed the team.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a team event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TeamAction {
+    /// A team was created.
+    Created,
+
+    /// A team was deleted.
+    Deleted,
+
+    /// A team's name, description, privacy or repository permissions changed.
+    Edited,
+
+    /// A team was given access to a repository.
+    AddedToRepository,
+
+    /// A team lost access to a repository.
+    RemovedFromRepository,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -0,0 +1,31 @@
+use github_derive::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Installation, Repository, SimpleOrg, Team, User};
+
+/// The payload of a team add event, this is sent when a repository is added to a
+/// team.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct TeamAddEvent<'a> {
+    /// The team the repository was added to.
+    #[serde(borrow)]
+    pub team: Team<'a>,
+
+    /// Detailed information about the repository that was added.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the team belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who added the repository.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
@@ -0,0 +1,423 @@
+//! Builders for webhook payloads, for tests and simulations.
+//!
+//! Each builder starts from a payload github sent, the ones in `test_json/`, so every
+//! field has a realistic value. The setters change what a test cares about along
+//! with the urls that mention it, `repo("o/r")` also turns
+//! `https://api.github.com/repos/Codertocat/Hello-World/pulls/2` into
+//! `https://api.github.com/repos/o/r/pulls/2`.
+//!
+//! `json` returns the payload as github would send it, sign it with
+//! [`sign`](crate::webhooks::verify::sign) to post it to a hook. `build` parses it.
+//!
+//! This module needs the `testing` feature.
+
+use js_int::UInt;
+use serde_json::Value;
+
+use crate::{
+    api::webhooks::{
+        issue::{IssueAction, IssueEvent},
+        pull::{PullRequestAction, PullRequestEvent},
+        push::PushEvent,
+    },
+    utils::IntoOwned,
+};
+
+/// A payload github sent that a builder edits.
+#[derive(Clone, Debug)]
+struct Payload(Value);
+
+impl Payload {
+    fn new(fixture: &str) -> Self {
+        Self(serde_json::from_str(fixture).expect("fixtures are valid JSON"))
+    }
+
+    fn get_mut(&mut self, pointer: &str) -> &mut Value {
+        self.0
+            .pointer_mut(pointer)
+            .unwrap_or_else(|| panic!("the payload has no `{}`", pointer))
+    }
+
+    fn set(&mut self, pointer: &str, value: impl Into<Value>) {
+        *self.get_mut(pointer) = value.into();
+    }
+
+    /// Sets the action, `changes` is only kept for `edited`.
+    fn action(&mut self, action: &str) {
+        self.set("/action", action);
+        if action != "edited" {
+            self.0.as_object_mut().unwrap().remove("changes");
+        }
+    }
+
+    /// Renames the repository everywhere it is mentioned.
+    fn repo(&mut self, full_name: &str) {
+        let (owner, name) =
+            full_name.split_once('/').expect("repositories are named `owner/repo`");
+        let old = self.0["repository"]["full_name"].as_str().unwrap().to_owned();
+        let old_owner = old.split_once('/').unwrap().0.to_owned();
+
+        replace(&mut self.0, &old, full_name);
+        rename_repos(&mut self.0, full_name, name, &old_owner, owner);
+    }
+
+    /// Renames the user at `pointer` along with its urls.
+    fn user(&mut self, pointer: &str, login: &str) {
+        let user = self.get_mut(pointer);
+        let old = user["login"]
+            .as_str()
+            .unwrap_or_else(|| panic!("`{}` is not a user", pointer))
+            .to_owned();
+        replace(user, &old, login);
+    }
+
+    /// Changes the number of the issue or pull request at `pointer` and its urls.
+    fn number(&mut self, pointer: &str, number: UInt) {
+        let item = self.get_mut(pointer);
+        let old = item["number"].to_string();
+        for path in ["/issues/", "/pull/", "/pulls/"] {
+            replace_path(
+                item,
+                &format!("{}{}", path, old),
+                &format!("{}{}", path, number),
+            );
+        }
+        item["number"] = u64::from(number).into();
+    }
+
+    fn json(&self) -> String { serde_json::to_string(&self.0).unwrap() }
+}
+
+/// Replaces `from` with `to` in every string of `value`.
+fn replace(value: &mut Value, from: &str, to: &str) {
+    match value {
+        Value::String(s) if s.contains(from) => *s = s.replace(from, to),
+        Value::Array(values) => values.iter_mut().for_each(|v| replace(v, from, to)),
+        Value::Object(map) => map.values_mut().for_each(|v| replace(v, from, to)),
+        _ => {}
+    }
+}
+
+/// Replaces `from` with `to` in every string of `value` where it is followed by a `/`
+/// or ends the string, so `/issues/1` does not match inside `/issues/12`.
+fn replace_path(value: &mut Value, from: &str, to: &str) {
+    match value {
+        Value::String(s) if s.contains(from) => {
+            let mut replaced = String::with_capacity(s.len());
+            let mut rest = s.as_str();
+            while let Some(idx) = rest.find(from) {
+                let after = &rest[idx + from.len()..];
+                replaced.push_str(&rest[..idx]);
+                replaced.push_str(if after.is_empty() || after.starts_with('/') {
+                    to
+                } else {
+                    from
+                });
+                rest = after;
+            }
+            replaced.push_str(rest);
+            *s = replaced;
+        }
+        Value::Array(values) => values.iter_mut().for_each(|v| replace_path(v, from, to)),
+        Value::Object(map) => map.values_mut().for_each(|v| replace_path(v, from, to)),
+        _ => {}
+    }
+}
+
+/// Renames every repository named `full_name` and its owner.
+fn rename_repos(
+    value: &mut Value,
+    full_name: &str,
+    name: &str,
+    old_owner: &str,
+    owner: &str,
+) {
+    match value {
+        Value::Object(map) => {
+            if map.get("full_name").and_then(Value::as_str) == Some(full_name) {
+                map.insert("name".to_owned(), name.into());
+                if let Some(user) = map.get_mut("owner") {
+                    replace(user, old_owner, owner);
+                }
+            }
+            map.values_mut()
+                .for_each(|v| rename_repos(v, full_name, name, old_owner, owner));
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|v| rename_repos(v, full_name, name, old_owner, owner)),
+        _ => {}
+    }
+}
+
+impl PullRequestEvent<'_> {
+    /// A builder for a pull request opened in `Codertocat/Hello-World`.
+    pub fn builder() -> PullRequestEventBuilder {
+        PullRequestEventBuilder(Payload::new(include_str!(
+            "../../../test_json/pull_request.json"
+        )))
+    }
+}
+
+/// Builds a [`PullRequestEvent`], see [`PullRequestEvent::builder`].
+#[derive(Clone, Debug)]
+pub struct PullRequestEventBuilder(Payload);
+
+impl PullRequestEventBuilder {
+    /// The action that was performed.
+    pub fn action(mut self, action: PullRequestAction) -> Self {
+        self.0.action(action.as_ref());
+        self
+    }
+
+    /// The repository the pull request is in, ie. `owner/repo`.
+    pub fn repo(mut self, full_name: &str) -> Self {
+        self.0.repo(full_name);
+        self
+    }
+
+    /// The user who opened the pull request, they are also the sender.
+    pub fn author(mut self, login: &str) -> Self {
+        self.0.user("/pull_request/user", login);
+        self.0.user("/sender", login);
+        self
+    }
+
+    /// The user who triggered the event.
+    pub fn sender(mut self, login: &str) -> Self {
+        self.0.user("/sender", login);
+        self
+    }
+
+    /// The number of the pull request.
+    pub fn number(mut self, number: UInt) -> Self {
+        self.0.number("/pull_request", number);
+        self.0.set("/number", u64::from(number));
+        self
+    }
+
+    /// The title of the pull request.
+    pub fn title(mut self, title: &str) -> Self {
+        self.0.set("/pull_request/title", title);
+        self
+    }
+
+    /// The body of the pull request.
+    pub fn body(mut self, body: &str) -> Self {
+        self.0.set("/pull_request/body", body);
+        self
+    }
+
+    /// The branch the pull request is merged into.
+    pub fn base(mut self, branch: &str) -> Self {
+        self.0.set("/pull_request/base/ref", branch);
+        self
+    }
+
+    /// The branch the pull request is merged from.
+    pub fn head(mut self, branch: &str) -> Self {
+        self.0.set("/pull_request/head/ref", branch);
+        self
+    }
+
+    /// Is the pull request a draft.
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.0.set("/pull_request/draft", draft);
+        self
+    }
+
+    /// Was the pull request merged, this is only meaningful when it was closed.
+    pub fn merged(mut self, merged: bool) -> Self {
+        self.0.set("/pull_request/merged", merged);
+        self
+    }
+
+    /// The payload as github sends it.
+    pub fn json(&self) -> String { self.0.json() }
+
+    /// Parses the payload.
+    pub fn build(&self) -> PullRequestEvent<'static> {
+        serde_json::from_str::<PullRequestEvent<'_>>(&self.json()).unwrap().into_owned()
+    }
+}
+
+impl IssueEvent<'_> {
+    /// A builder for an issue opened in `Codertocat/Hello-World`.
+    pub fn builder() -> IssueEventBuilder {
+        IssueEventBuilder(Payload::new(include_str!("../../../test_json/issue.json")))
+            .action(IssueAction::Opened)
+    }
+}
+
+/// Builds an [`IssueEvent`], see [`IssueEvent::builder`].
+#[derive(Clone, Debug)]
+pub struct IssueEventBuilder(Payload);
+
+impl IssueEventBuilder {
+    /// The action that was performed.
+    pub fn action(mut self, action: IssueAction) -> Self {
+        self.0.action(action.as_ref());
+        self
+    }
+
+    /// The repository the issue is in, ie. `owner/repo`.
+    pub fn repo(mut self, full_name: &str) -> Self {
+        self.0.repo(full_name);
+        self
+    }
+
+    /// The user who opened the issue, they are also the sender.
+    pub fn author(mut self, login: &str) -> Self {
+        self.0.user("/issue/user", login);
+        self.0.user("/sender", login);
+        self
+    }
+
+    /// The user who triggered the event.
+    pub fn sender(mut self, login: &str) -> Self {
+        self.0.user("/sender", login);
+        self
+    }
+
+    /// The number of the issue.
+    pub fn number(mut self, number: UInt) -> Self {
+        self.0.number("/issue", number);
+        self
+    }
+
+    /// The title of the issue.
+    pub fn title(mut self, title: &str) -> Self {
+        self.0.set("/issue/title", title);
+        self
+    }
+
+    /// The body of the issue.
+    pub fn body(mut self, body: &str) -> Self {
+        self.0.set("/issue/body", body);
+        self
+    }
+
+    /// The payload as github sends it.
+    pub fn json(&self) -> String { self.0.json() }
+
+    /// Parses the payload.
+    pub fn build(&self) -> IssueEvent<'static> {
+        serde_json::from_str::<IssueEvent<'_>>(&self.json()).unwrap().into_owned()
+    }
+}
+
+impl PushEvent<'_> {
+    /// A builder for a push of 4 commits to the `readme` branch of `DevinR528/magit`.
+    pub fn builder() -> PushEventBuilder {
+        PushEventBuilder(Payload::new(include_str!("../../../test_json/real_push.json")))
+    }
+}
+
+/// Builds a [`PushEvent`], see [`PushEvent::builder`].
+#[derive(Clone, Debug)]
+pub struct PushEventBuilder(Payload);
+
+impl PushEventBuilder {
+    /// The repository that was pushed to, ie. `owner/repo`.
+    pub fn repo(mut self, full_name: &str) -> Self {
+        self.0.repo(full_name);
+        self
+    }
+
+    /// The user who pushed, they are also the sender.
+    pub fn pusher(mut self, login: &str) -> Self {
+        let pusher = self.0.get_mut("/pusher");
+        let old = pusher["name"].as_str().unwrap().to_owned();
+        replace(pusher, &old, login);
+        self.0.user("/sender", login);
+        self
+    }
+
+    /// The ref that was pushed, ie. `refs/heads/main`.
+    pub fn git_ref(mut self, git_ref: &str) -> Self {
+        self.0.set("/ref", git_ref);
+        self
+    }
+
+    /// Keeps the first `count` commits, commits are repeated when there are not
+    /// enough.
+    pub fn commits(mut self, count: usize) -> Self {
+        let commits = self.0.get_mut("/commits").as_array_mut().unwrap();
+        *commits = commits.iter().cycle().take(count).cloned().collect();
+        self
+    }
+
+    /// The payload as github sends it.
+    pub fn json(&self) -> String { self.0.json() }
+
+    /// Parses the payload.
+    pub fn build(&self) -> PushEvent<'static> {
+        serde_json::from_str::<PushEvent<'_>>(&self.json()).unwrap().into_owned()
+    }
+}
+
+#[test]
+fn pull_request_builder() {
+    use js_int::uint;
+
+    let builder = PullRequestEvent::builder()
+        .action(PullRequestAction::Closed)
+        .repo("o/r")
+        .author("bob")
+        .number(uint!(7))
+        .merged(true);
+    let pull = builder.build();
+
+    assert!(matches!(pull.action, PullRequestAction::Closed));
+    assert_eq!(pull.repository.full_name, "o/r");
+    assert_eq!(pull.repository.name, "r");
+    assert_eq!(pull.repository.owner.login, "o");
+    assert_eq!(pull.pull_request.user.login, "bob");
+    assert_eq!(pull.sender.login, "bob");
+    assert_eq!(pull.pull_request.number, uint!(7));
+    assert_eq!(pull.pull_request.html_url.as_str(), "https://github.com/o/r/pull/7");
+    assert_eq!(pull.pull_request.merged, Some(true));
+
+    // Keys that no field captures are still sent
+    let json: Value = serde_json::from_str(&builder.json()).unwrap();
+    assert_eq!(
+        json["pull_request"]["issue_url"],
+        "https://api.github.com/repos/o/r/issues/7"
+    );
+}
+
+#[test]
+fn issue_builder() {
+    let issue = IssueEvent::builder().repo("o/r").author("bob").title("broken").build();
+
+    assert!(matches!(issue.action, IssueAction::Opened));
+    assert!(issue.changes.is_none());
+    assert_eq!(issue.issue.user.login, "bob");
+    assert_eq!(issue.issue.title, "broken");
+    assert_eq!(issue.issue.html_url.as_str(), "https://github.com/o/r/issues/1");
+}
+
+#[test]
+fn issue_number_urls() {
+    use js_int::uint;
+
+    let body = "dup of https://github.com/o/r/issues/12";
+    let issue = IssueEvent::builder().repo("o/r").body(body).number(uint!(5)).build();
+
+    assert_eq!(issue.issue.number, uint!(5));
+    assert_eq!(issue.issue.html_url.as_str(), "https://github.com/o/r/issues/5");
+    assert_eq!(issue.issue.body, body);
+}
+
+#[test]
+fn push_builder() {
+    let push = PushEvent::builder().repo("o/r").pusher("bob").commits(300).build();
+
+    assert_eq!(push.commits.len(), 300);
+    assert_eq!(push.pusher.name.as_deref(), Some("bob"));
+    assert_eq!(push.sender.login, "bob");
+    assert_eq!(
+        push.compare,
+        "https://github.com/o/r/compare/75f058ee0547...fb1fe12c1c4c"
+    );
+}
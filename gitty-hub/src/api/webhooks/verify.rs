@@ -0,0 +1,301 @@
+//! Verification of the signature github sends with every webhook delivery.
+//!
+//! Github signs the raw request body with an HMAC keyed by the hook's secret and
+//! sends the hex encoded digest as `sha256=<digest>` in the `X-Hub-Signature-256`
+//! header and as `sha1=<digest>` in the legacy `X-Hub-Signature` header.
+
+use hmac::{Hmac, Mac, NewMac};
+use sha1::Sha1;
+use sha2::Sha256;
+use thiserror::Error;
+
+/// The header carrying the `sha256=` signature of a webhook payload.
+pub const X_HUB_SIGNATURE_256: &str = "x-hub-signature-256";
+
+/// The legacy header carrying the `sha1=` signature of a webhook payload.
+pub const X_HUB_SIGNATURE: &str = "x-hub-signature";
+
+/// The hash function a signature was made with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// HMAC SHA-256, sent in the `X-Hub-Signature-256` header.
+    Sha256,
+
+    /// HMAC SHA-1, sent in the legacy `X-Hub-Signature` header.
+    Sha1,
+}
+
+impl Algorithm {
+    /// The prefix github puts before the hex digest, without the `=`.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha1 => "sha1",
+        }
+    }
+
+    /// The header github sends signatures made with this algorithm in.
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::Sha256 => X_HUB_SIGNATURE_256,
+            Self::Sha1 => X_HUB_SIGNATURE,
+        }
+    }
+}
+
+/// Errors that can happen while verifying a webhook signature.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum VerifyError {
+    /// The verifier was created without any secrets.
+    #[error("no webhook secret has been configured")]
+    NoSecret,
+
+    /// The signature is not in the `<algorithm>=<digest>` form.
+    #[error("signature is missing the `sha256=` or `sha1=` prefix")]
+    MissingPrefix,
+
+    /// The signature names an algorithm other than `sha256` or `sha1`.
+    #[error("unsupported signature algorithm `{0}`")]
+    UnsupportedAlgorithm(String),
+
+    /// The signature names a different algorithm than the header it was sent in, ie. a
+    /// `sha1=` signature in `X-Hub-Signature-256`.
+    #[error("expected a `{}=` signature, found `{}=`", .expected.prefix(), .found.prefix())]
+    AlgorithmMismatch {
+        /// The algorithm of the header the signature was sent in.
+        expected: Algorithm,
+
+        /// The algorithm the signature names.
+        found: Algorithm,
+    },
+
+    /// The digest is not valid hex.
+    #[error("signature digest is not valid hex")]
+    InvalidHex,
+
+    /// The digest does not match the payload for any of the secrets.
+    #[error("signature does not match the payload")]
+    Mismatch,
+}
+
+/// Checks webhook signatures against one or more secrets.
+///
+/// More than one secret can be given so a hook's secret can be rotated without
+/// dropping deliveries that were signed with the previous one.
+#[derive(Clone, Debug, Default)]
+pub struct WebhookVerifier {
+    secrets: Vec<Vec<u8>>,
+}
+
+impl WebhookVerifier {
+    /// Create a verifier that accepts signatures made with `secret`.
+    pub fn new<S: AsRef<[u8]>>(secret: S) -> Self {
+        Self { secrets: vec![secret.as_ref().to_vec()] }
+    }
+
+    /// Create a verifier that accepts signatures made with any of `secrets`.
+    pub fn with_secrets<I, S>(secrets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        Self { secrets: secrets.into_iter().map(|s| s.as_ref().to_vec()).collect() }
+    }
+
+    /// Verify `signature`, the value of the header for `algorithm`, against the raw
+    /// request `body`.
+    ///
+    /// The algorithm comes from the header the signature was sent in, a signature that
+    /// names another algorithm is rejected so a `sha1=` signature can not be passed off
+    /// in `X-Hub-Signature-256`.
+    ///
+    /// The digest is compared in constant time and every secret is checked, so the
+    /// time taken does not reveal how much of the digest or which secret matched.
+    pub fn verify<B: AsRef<[u8]>>(
+        &self,
+        algorithm: Algorithm,
+        signature: &str,
+        body: B,
+    ) -> Result<(), VerifyError> {
+        if self.secrets.is_empty() {
+            return Err(VerifyError::NoSecret);
+        }
+
+        let (prefix, digest) =
+            signature.split_once('=').ok_or(VerifyError::MissingPrefix)?;
+        let found = match prefix {
+            "sha256" => Algorithm::Sha256,
+            "sha1" => Algorithm::Sha1,
+            other => return Err(VerifyError::UnsupportedAlgorithm(other.to_owned())),
+        };
+        if found != algorithm {
+            return Err(VerifyError::AlgorithmMismatch { expected: algorithm, found });
+        }
+        let digest = decode_hex(digest).ok_or(VerifyError::InvalidHex)?;
+
+        let body = body.as_ref();
+        let mut matched = false;
+        for secret in &self.secrets {
+            matched |= match algorithm {
+                Algorithm::Sha256 => mac_matches::<Hmac<Sha256>>(secret, body, &digest),
+                Algorithm::Sha1 => mac_matches::<Hmac<Sha1>>(secret, body, &digest),
+            };
+        }
+
+        if matched {
+            Ok(())
+        } else {
+            Err(VerifyError::Mismatch)
+        }
+    }
+}
+
+/// Sign `body` with `secret` the way github does, ie. `sha256=<hex digest>`.
+///
+/// This is mostly useful for building requests in tests.
+pub fn sign<S: AsRef<[u8]>, B: AsRef<[u8]>>(
+    algorithm: Algorithm,
+    secret: S,
+    body: B,
+) -> String {
+    let (secret, body) = (secret.as_ref(), body.as_ref());
+    let digest = match algorithm {
+        Algorithm::Sha256 => mac_digest::<Hmac<Sha256>>(secret, body),
+        Algorithm::Sha1 => mac_digest::<Hmac<Sha1>>(secret, body),
+    };
+    format!("{}={}", algorithm.prefix(), encode_hex(&digest))
+}
+
+fn new_mac<M: Mac + NewMac>(secret: &[u8], body: &[u8]) -> M {
+    let mut mac = M::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac
+}
+
+fn mac_matches<M: Mac + NewMac>(secret: &[u8], body: &[u8], digest: &[u8]) -> bool {
+    // `Mac::verify` compares in constant time
+    new_mac::<M>(secret, body).verify(digest).is_ok()
+}
+
+fn mac_digest<M: Mac + NewMac>(secret: &[u8], body: &[u8]) -> Vec<u8> {
+    new_mac::<M>(secret, body).finalize().into_bytes().to_vec()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    const CHARS: &[u8] = b"0123456789abcdef";
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        s.push(CHARS[(byte >> 4) as usize] as char);
+        s.push(CHARS[(byte & 0xf) as usize] as char);
+    }
+    s
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs
+        .map(|pair| {
+            let hi = (pair[0] as char).to_digit(16)?;
+            let lo = (pair[1] as char).to_digit(16)?;
+            Some((hi << 4 | lo) as u8)
+        })
+        .collect()
+}
+
+#[test]
+fn verify_github_example() {
+    // The example from github's "Validating webhook deliveries" docs
+    let verifier = WebhookVerifier::new("It's a Secret to Everybody");
+    assert_eq!(
+        verifier.verify(
+            Algorithm::Sha256,
+            "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+            "Hello, World!",
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn verify_signed() {
+    let body = include_str!("../../../test_json/push.json");
+    let verifier = WebhookVerifier::new("secret");
+
+    let sha256 = sign(Algorithm::Sha256, "secret", body);
+    assert!(sha256.starts_with("sha256="));
+    assert_eq!(verifier.verify(Algorithm::Sha256, &sha256, body), Ok(()));
+
+    let sha1 = sign(Algorithm::Sha1, "secret", body);
+    assert!(sha1.starts_with("sha1="));
+    assert_eq!(verifier.verify(Algorithm::Sha1, &sha1, body), Ok(()));
+
+    assert_eq!(
+        verifier.verify(Algorithm::Sha256, &sha256, "{}"),
+        Err(VerifyError::Mismatch)
+    );
+    assert_eq!(
+        verifier.verify(Algorithm::Sha256, &sign(Algorithm::Sha256, "wrong", body), body),
+        Err(VerifyError::Mismatch)
+    );
+}
+
+#[test]
+fn verify_algorithm_of_header() {
+    let body = include_str!("../../../test_json/push.json");
+    let verifier = WebhookVerifier::new("secret");
+
+    // A valid sha1 signature sent in the sha256 header is a downgrade
+    let sha1 = sign(Algorithm::Sha1, "secret", body);
+    assert_eq!(
+        verifier.verify(Algorithm::Sha256, &sha1, body),
+        Err(VerifyError::AlgorithmMismatch {
+            expected: Algorithm::Sha256,
+            found: Algorithm::Sha1
+        })
+    );
+    let sha256 = sign(Algorithm::Sha256, "secret", body);
+    assert!(verifier.verify(Algorithm::Sha1, &sha256, body).is_err());
+}
+
+#[test]
+fn verify_rotated_secrets() {
+    let verifier = WebhookVerifier::with_secrets(["new", "old"]);
+    let verify = |secret| {
+        verifier.verify(Algorithm::Sha256, &sign(Algorithm::Sha256, secret, "{}"), "{}")
+    };
+
+    assert_eq!(verify("new"), Ok(()));
+    assert_eq!(verify("old"), Ok(()));
+    assert_eq!(verify("older"), Err(VerifyError::Mismatch));
+    assert_eq!(
+        WebhookVerifier::default().verify(
+            Algorithm::Sha256,
+            &sign(Algorithm::Sha256, "new", "{}"),
+            "{}"
+        ),
+        Err(VerifyError::NoSecret)
+    );
+}
+
+#[test]
+fn verify_malformed() {
+    let verifier = WebhookVerifier::new("secret");
+    let verify = |signature| verifier.verify(Algorithm::Sha256, signature, "{}");
+
+    assert_eq!(verify(""), Err(VerifyError::MissingPrefix));
+    assert_eq!(verify("sha"), Err(VerifyError::MissingPrefix));
+    assert_eq!(
+        verify("md5=abcd"),
+        Err(VerifyError::UnsupportedAlgorithm("md5".to_owned()))
+    );
+    assert_eq!(verify("sha256=abc"), Err(VerifyError::InvalidHex));
+    assert_eq!(verify("sha256=zz"), Err(VerifyError::InvalidHex));
+    assert_eq!(verify("sha256="), Err(VerifyError::Mismatch));
+    assert_eq!(
+        verifier.verify(Algorithm::Sha1, "sha1=abcd", "{}"),
+        Err(VerifyError::Mismatch)
+    );
+}
@@ -1,10 +1,10 @@
-use github_derive::StringEnum;
-use serde::Deserialize;
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
 
-use crate::api::{Installation, Org, Repository, User};
+use crate::api::{Installation, Repository, SimpleOrg, User};
 
 /// The payload of a delete event.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
 pub struct WatchEvent<'a> {
     /// The action that was performed.
     pub action: WatchAction,
@@ -16,7 +16,7 @@ pub struct WatchEvent<'a> {
     /// Detailed information about the organization the app
     /// belongs to.
     #[serde(borrow)]
-    pub organization: Option<Org<'a>>,
+    pub organization: Option<SimpleOrg<'a>>,
 
     /// Information about Github app installation.
     ///
@@ -38,4 +38,6 @@ pub struct WatchEvent<'a> {
 pub enum WatchAction {
     /// A new watcher was added.
     Started,
+    #[doc(hidden)]
+    _Custom(String),
 }
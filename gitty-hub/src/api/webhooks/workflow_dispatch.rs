@@ -0,0 +1,44 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use github_derive::IntoOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{Installation, Repository, SimpleOrg, User},
+    utils::deserialize_cow_str,
+};
+
+/// The payload of a workflow dispatch event, this is sent when a workflow is run
+/// manually.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct WorkflowDispatchEvent<'a> {
+    /// The inputs the workflow was run with.
+    pub inputs: Option<BTreeMap<String, serde_json::Value>>,
+
+    /// The branch or tag the workflow was run on.
+    #[serde(rename = "ref")]
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub ref_: Cow<'a, str>,
+
+    /// The path of the workflow file, ie. `.github/workflows/release.yml`.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub workflow: Cow<'a, str>,
+
+    /// Detailed information about the repository the workflow belongs to.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who ran the workflow.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+
+use github_derive::{IntoOwned, StringEnum};
+use js_int::UInt;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{
+        CheckStatus, ConclusionStatus, Installation, Repository, SimpleOrg, Timestamp,
+        User,
+    },
+    utils::{deserialize_cow_str, deserialize_cow_str_opt},
+};
+
+/// The payload of a workflow job event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct WorkflowJobEvent<'a> {
+    /// The action that was performed.
+    pub action: WorkflowJobAction,
+
+    /// The job of a workflow run.
+    #[serde(borrow)]
+    pub workflow_job: WorkflowJob<'a>,
+
+    /// Detailed information about the repository the job ran in.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the run.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a workflow job event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum WorkflowJobAction {
+    /// A job was created and is waiting for a runner.
+    Queued,
+
+    /// A job is waiting for a deployment protection rule to pass.
+    Waiting,
+
+    /// A runner picked up the job.
+    InProgress,
+
+    /// A job finished, the result is in the job's `conclusion`.
+    Completed,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
+
+/// Information about a job of a workflow run.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct WorkflowJob<'a> {
+    /// Numeric Id of this job.
+    pub id: UInt,
+
+    /// Numeric Id of the run this job is a part of.
+    pub run_id: UInt,
+
+    /// Which attempt of the run this job belongs to, starting at 1.
+    pub run_attempt: Option<UInt>,
+
+    /// The github api url of the run this job is a part of.
+    pub run_url: Url,
+
+    /// String identifier of the job.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub node_id: Cow<'a, str>,
+
+    /// The SHA of the commit the job ran on.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub head_sha: Cow<'a, str>,
+
+    /// The github api url of this job.
+    pub url: Url,
+
+    /// The public web page of this job.
+    pub html_url: Url,
+
+    /// The state of the job.
+    pub status: CheckStatus,
+
+    /// The result of the job, this is only present once the job has completed.
+    pub conclusion: Option<ConclusionStatus>,
+
+    /// The time in UTC when the job started.
+    pub started_at: Timestamp,
+
+    /// The time in UTC when the job completed.
+    pub completed_at: Option<Timestamp>,
+
+    /// The name of the job.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The steps of this job.
+    #[serde(default, borrow)]
+    pub steps: Vec<WorkflowStep<'a>>,
+
+    /// The github api url of the check run of this job.
+    pub check_run_url: Url,
+
+    /// The labels a runner must have to pick up this job.
+    #[serde(default)]
+    pub labels: Vec<Cow<'a, str>>,
+
+    /// The Id of the runner that picked up this job.
+    pub runner_id: Option<UInt>,
+
+    /// The name of the runner that picked up this job.
+    #[serde(default, borrow, deserialize_with = "deserialize_cow_str_opt")]
+    pub runner_name: Option<Cow<'a, str>>,
+}
+
+impl WorkflowJob<'_> {
+    /// The first step that failed, if any.
+    pub fn failed_step(&self) -> Option<&WorkflowStep<'_>> {
+        self.steps
+            .iter()
+            .find(|step| matches!(step.conclusion, Some(ConclusionStatus::Failure)))
+    }
+}
+
+/// A step of a workflow job.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct WorkflowStep<'a> {
+    /// The name of the step.
+    #[serde(borrow, deserialize_with = "deserialize_cow_str")]
+    pub name: Cow<'a, str>,
+
+    /// The state of the step.
+    pub status: CheckStatus,
+
+    /// The result of the step, this is only present once the step has completed.
+    pub conclusion: Option<ConclusionStatus>,
+
+    /// The position of this step in the job, starting at 1.
+    pub number: UInt,
+
+    /// The time in UTC when the step started.
+    pub started_at: Option<Timestamp>,
+
+    /// The time in UTC when the step completed.
+    pub completed_at: Option<Timestamp>,
+}
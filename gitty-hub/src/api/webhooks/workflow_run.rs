@@ -0,0 +1,55 @@
+use github_derive::{IntoOwned, StringEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{Installation, Repository, SimpleOrg, User, Workflow, WorkflowRun};
+
+/// The payload of a workflow run event.
+#[derive(Clone, Debug, Deserialize, Serialize, IntoOwned)]
+pub struct WorkflowRunEvent<'a> {
+    /// The action that was performed.
+    pub action: WorkflowRunAction,
+
+    /// The run of the workflow.
+    #[serde(borrow)]
+    pub workflow_run: WorkflowRun<'a>,
+
+    /// The workflow that was run.
+    #[serde(borrow)]
+    pub workflow: Workflow<'a>,
+
+    /// Detailed information about the repository the workflow ran in.
+    #[serde(borrow)]
+    pub repository: Repository<'a>,
+
+    /// Information about Github app installation.
+    ///
+    /// This is only present if the event is sent from said app.
+    #[serde(borrow)]
+    pub installation: Option<Installation<'a>>,
+
+    /// Detailed information about the organization the repo belongs to.
+    #[serde(borrow)]
+    pub organization: Option<SimpleOrg<'a>>,
+
+    /// Detailed information about the user who triggered the run.
+    #[serde(borrow)]
+    pub sender: User<'a>,
+}
+
+/// The specific actions that a workflow run event has.
+#[derive(Clone, Debug, StringEnum)]
+#[github_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum WorkflowRunAction {
+    /// A workflow run was created.
+    Requested,
+
+    /// A workflow run started running.
+    InProgress,
+
+    /// A workflow run finished, the result is in the run's `conclusion`.
+    Completed,
+
+    #[doc(hidden)]
+    _Custom(String),
+}
@@ -11,6 +11,8 @@ extern crate self as gitty_hub;
 pub mod api;
 pub mod utils;
 
+pub use api::webhooks;
+
 pub const USER_AGENT: &str =
     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
@@ -41,6 +43,9 @@ pub enum GithubFailure {
 
     #[error("Request failed: {} CODE: {}",  .0.as_str(), .0.as_u16())]
     StatusError(StatusCode),
+
+    #[error("GraphQL request failed: {0}")]
+    GraphQl(String),
 }
 
 pub fn from_status(status: StatusCode) -> Result<(), Error> {
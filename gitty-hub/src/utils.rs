@@ -2,6 +2,55 @@ use std::{borrow::Cow, str};
 
 use serde::de::{self, Deserializer, Unexpected, Visitor};
 
+/// Convert a value that may borrow from a webhook request into one that owns all of
+/// its data.
+///
+/// This is implemented for every webhook payload with `#[derive(IntoOwned)]`, the
+/// owned value can be sent to another thread or stored after the request is gone.
+pub trait IntoOwned {
+    /// The `'static` version of `Self`.
+    type Owned: 'static;
+
+    /// Convert `self` into a value that does not borrow.
+    fn into_owned(self) -> Self::Owned;
+}
+
+impl<'a, T> IntoOwned for Cow<'a, T>
+where
+    T: ToOwned + ?Sized + 'static,
+    T::Owned: 'static,
+{
+    type Owned = Cow<'static, T>;
+
+    fn into_owned(self) -> Cow<'static, T> { Cow::Owned(Cow::into_owned(self)) }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned { self.map(IntoOwned::into_owned) }
+}
+
+impl<T: IntoOwned> IntoOwned for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Box<T> {
+    type Owned = Box<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned { Box::new((*self).into_owned()) }
+}
+
+impl IntoOwned for serde_json::Value {
+    type Owned = Self;
+
+    fn into_owned(self) -> Self::Owned { self }
+}
+
 /// Deserialize a `Cow<'de, str>`.
 ///
 /// Different from serde's implementation of `Deserialize` for `Cow` since it borrows from
@@ -16,6 +65,95 @@ where
     deserializer.deserialize_string(CowStrVisitor)
 }
 
+/// Deserialize an `Option<Cow<'de, str>>`, borrowing from the input when possible.
+///
+/// Use this with `#[serde(default)]` so a missing field is `None`.
+pub fn deserialize_cow_str_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<Cow<'de, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(OptCowStrVisitor)
+}
+
+/// Deserialize a `Vec<Cow<'de, str>>`, borrowing each string from the input when
+/// possible.
+///
+/// Use this with `#[serde(default)]` so a missing field is empty.
+pub fn deserialize_cow_str_vec<'de, D>(
+    deserializer: D,
+) -> Result<Vec<Cow<'de, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(VecCowStrVisitor)
+}
+
+struct OptCowStrVisitor;
+
+impl<'de> Visitor<'de> for OptCowStrVisitor {
+    type Value = Option<Cow<'de, str>>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a string or null")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_cow_str(deserializer).map(Some)
+    }
+}
+
+struct VecCowStrVisitor;
+
+impl<'de> Visitor<'de> for VecCowStrVisitor {
+    type Value = Vec<Cow<'de, str>>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a list of strings")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut strs = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(BorrowedStr(s)) = seq.next_element()? {
+            strs.push(s);
+        }
+        Ok(strs)
+    }
+}
+
+/// A string element of a list that borrows from the input when possible.
+struct BorrowedStr<'de>(Cow<'de, str>);
+
+impl<'de> serde::Deserialize<'de> for BorrowedStr<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_cow_str(deserializer).map(BorrowedStr)
+    }
+}
+
 struct CowStrVisitor;
 
 impl<'de> Visitor<'de> for CowStrVisitor {
@@ -0,0 +1,350 @@
+//! Drops webhook deliveries that github already sent us.
+//!
+//! Github redelivers a hook when we time out and when someone clicks "Redeliver",
+//! both keep the `X-GitHub-Delivery` GUID of the original delivery. The GUIDs that
+//! were forwarded recently are kept in a bounded, time-windowed LRU and optionally
+//! in a [`DedupBackend`] so a restart does not forget them. A GUID is only recorded
+//! once forwarding it succeeded, until then it is in flight and only kept in memory.
+
+use std::{
+    collections::HashSet,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use tracing::warn;
+
+/// How deliveries are deduplicated.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DedupConfig {
+    /// The most deliveries that are remembered, the least recently seen are
+    /// forgotten first.
+    #[serde(default = "default_capacity")]
+    pub capacity: usize,
+
+    /// How long in seconds a delivery is remembered.
+    #[serde(default = "default_window")]
+    pub window: u64,
+
+    /// The file deliveries are persisted to, if not set they are only kept in memory.
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+}
+
+impl Default for DedupConfig {
+    fn default() -> Self {
+        Self { capacity: default_capacity(), window: default_window(), path: None }
+    }
+}
+
+fn default_capacity() -> usize { 1024 }
+
+fn default_window() -> u64 { 60 * 60 * 24 }
+
+/// Somewhere deliveries are persisted between restarts.
+pub trait DedupBackend: Send + Sync {
+    /// Returns every delivery that was recorded, oldest first.
+    fn load(&self) -> io::Result<Vec<(String, SystemTime)>>;
+
+    /// Records a delivery that was forwarded.
+    fn record(&self, id: &str, seen: SystemTime) -> io::Result<()>;
+
+    /// Replaces every recorded delivery with `entries`, this keeps the backend bounded.
+    fn compact(&self, entries: &[(&str, SystemTime)]) -> io::Result<()>;
+}
+
+/// Persists deliveries to a file, one `<unix seconds> <GUID>` line per delivery.
+#[derive(Debug)]
+pub struct FileBackend {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl FileBackend {
+    /// Opens or creates the file at `path`.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self { path, file: Mutex::new(file) })
+    }
+}
+
+impl DedupBackend for FileBackend {
+    fn load(&self) -> io::Result<Vec<(String, SystemTime)>> {
+        let file = File::open(&self.path)?;
+        let mut entries = vec![];
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            // A line cut short by a crash is skipped
+            if let Some((secs, id)) = line.split_once(' ') {
+                if let Ok(secs) = secs.parse() {
+                    entries.push((id.to_owned(), UNIX_EPOCH + Duration::from_secs(secs)));
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    fn record(&self, id: &str, seen: SystemTime) -> io::Result<()> {
+        let secs = seen.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let mut file = self.file.lock().unwrap();
+        writeln!(file, "{} {}", secs, id)?;
+        file.flush()
+    }
+
+    fn compact(&self, entries: &[(&str, SystemTime)]) -> io::Result<()> {
+        let mut file = self.file.lock().unwrap();
+
+        let tmp = self.path.with_extension("tmp");
+        let mut out = File::create(&tmp)?;
+        for (id, seen) in entries {
+            let secs = seen.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+            writeln!(out, "{} {}", secs, id)?;
+        }
+        out.sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+
+        *file = OpenOptions::new().append(true).open(&self.path)?;
+        Ok(())
+    }
+}
+
+/// What [`Dedup::check`] knows about a delivery.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Check {
+    /// The delivery was not forwarded yet, it is now in flight until it is passed to
+    /// [`Dedup::mark_delivered`] or [`Dedup::abandon`].
+    New,
+    /// The delivery was already forwarded within the window.
+    Delivered,
+    /// The delivery is being forwarded by another request right now.
+    InFlight,
+}
+
+/// Remembers the GUIDs of recently forwarded deliveries.
+pub struct Dedup {
+    capacity: usize,
+    window: Duration,
+    seen: Mutex<Seen>,
+    backend: Option<Box<dyn DedupBackend>>,
+}
+
+struct Seen {
+    /// The time each delivery was first seen, the least recently seen is first.
+    entries: IndexMap<String, SystemTime>,
+    /// The number of records written to the backend since it was last compacted.
+    recorded: usize,
+    /// The deliveries that are being forwarded.
+    in_flight: HashSet<String>,
+}
+
+impl Dedup {
+    /// Creates an in-memory deduplicator.
+    pub fn new(capacity: usize, window: Duration) -> Self {
+        Self {
+            capacity,
+            window,
+            seen: Mutex::new(Seen {
+                entries: IndexMap::new(),
+                recorded: 0,
+                in_flight: HashSet::new(),
+            }),
+            backend: None,
+        }
+    }
+
+    /// Creates a deduplicator that persists deliveries to `backend`, the deliveries it
+    /// already recorded are loaded.
+    pub fn with_backend(
+        capacity: usize,
+        window: Duration,
+        backend: Box<dyn DedupBackend>,
+    ) -> io::Result<Self> {
+        let mut dedup = Self::new(capacity, window);
+        let now = SystemTime::now();
+        {
+            let seen = dedup.seen.get_mut().unwrap();
+            for (id, at) in backend.load()? {
+                seen.entries.shift_remove(&id);
+                seen.entries.insert(id, at);
+            }
+            seen.prune(now, capacity, window);
+            // Start every run from a file that only holds what is still remembered
+            seen.compact(&*backend)?;
+        }
+        dedup.backend = Some(backend);
+        Ok(dedup)
+    }
+
+    /// Creates a deduplicator from the config, with a [`FileBackend`] if a path is set.
+    pub fn from_config(config: &DedupConfig) -> io::Result<Self> {
+        let window = Duration::from_secs(config.window);
+        match &config.path {
+            Some(path) => Self::with_backend(
+                config.capacity,
+                window,
+                Box::new(FileBackend::open(path)?),
+            ),
+            None => Ok(Self::new(config.capacity, window)),
+        }
+    }
+
+    /// Checks whether the delivery `id` should be forwarded.
+    ///
+    /// A [`Check::New`] delivery is in flight, any other check of it returns
+    /// [`Check::InFlight`] until it is marked as delivered or abandoned.
+    pub fn check(&self, id: &str) -> Check { self.check_at(id, SystemTime::now()) }
+
+    fn check_at(&self, id: &str, now: SystemTime) -> Check {
+        let mut seen = self.seen.lock().unwrap();
+        seen.prune(now, self.capacity, self.window);
+
+        if let Some(at) = seen.entries.shift_remove(id) {
+            // Keep the time it was first seen so redeliveries can't extend the window
+            seen.entries.insert(id.to_owned(), at);
+            return Check::Delivered;
+        }
+        if !seen.in_flight.insert(id.to_owned()) {
+            return Check::InFlight;
+        }
+        Check::New
+    }
+
+    /// Remembers that the delivery `id` was forwarded, this is what persists it.
+    pub fn mark_delivered(&self, id: &str) {
+        self.mark_delivered_at(id, SystemTime::now())
+    }
+
+    fn mark_delivered_at(&self, id: &str, now: SystemTime) {
+        let mut seen = self.seen.lock().unwrap();
+        seen.in_flight.remove(id);
+
+        seen.entries.shift_remove(id);
+        seen.entries.insert(id.to_owned(), now);
+        if seen.entries.len() > self.capacity {
+            seen.entries.shift_remove_index(0);
+        }
+
+        if let Some(backend) = &self.backend {
+            seen.recorded += 1;
+            let res = if seen.recorded > self.capacity {
+                seen.compact(&**backend)
+            } else {
+                backend.record(id, now)
+            };
+            if let Err(err) = res {
+                warn!("failed to persist delivery {}: {}", id, err);
+            }
+        }
+    }
+
+    /// Stops tracking the in flight delivery `id` so a redelivery of it is forwarded.
+    ///
+    /// This is used when forwarding a delivery failed.
+    pub fn abandon(&self, id: &str) { self.seen.lock().unwrap().in_flight.remove(id); }
+}
+
+impl Seen {
+    /// Forgets the deliveries outside the window and the least recently seen ones
+    /// over capacity.
+    fn prune(&mut self, now: SystemTime, capacity: usize, window: Duration) {
+        self.entries.retain(|_, at| {
+            now.duration_since(*at).map_or(true, |elapsed| elapsed < window)
+        });
+        while self.entries.len() > capacity {
+            self.entries.shift_remove_index(0);
+        }
+    }
+
+    /// Replaces what `backend` recorded with the deliveries that are remembered.
+    fn compact(&mut self, backend: &dyn DedupBackend) -> io::Result<()> {
+        self.recorded = 0;
+        let entries =
+            self.entries.iter().map(|(id, at)| (&**id, *at)).collect::<Vec<_>>();
+        backend.compact(&entries)
+    }
+}
+
+#[cfg(test)]
+fn forward(dedup: &Dedup, id: &str, now: SystemTime) -> bool {
+    let check = dedup.check_at(id, now);
+    if check == Check::New {
+        dedup.mark_delivered_at(id, now);
+    }
+    check == Check::New
+}
+
+#[test]
+fn dedup_window() {
+    let dedup = Dedup::new(8, Duration::from_secs(60));
+    let now = SystemTime::now();
+
+    assert!(forward(&dedup, "a", now));
+    assert!(!forward(&dedup, "a", now + Duration::from_secs(30)));
+    assert!(forward(&dedup, "a", now + Duration::from_secs(61)));
+}
+
+#[test]
+fn dedup_capacity() {
+    let dedup = Dedup::new(2, Duration::from_secs(60));
+    let now = SystemTime::now();
+
+    assert!(forward(&dedup, "a", now));
+    assert!(forward(&dedup, "b", now));
+    // Seeing `a` again makes `b` the least recently seen
+    assert!(!forward(&dedup, "a", now));
+    assert!(forward(&dedup, "c", now));
+    assert!(!forward(&dedup, "a", now));
+    assert!(forward(&dedup, "b", now));
+}
+
+#[test]
+fn dedup_in_flight() {
+    let dedup = Dedup::new(8, Duration::from_secs(60));
+    let now = SystemTime::now();
+
+    assert_eq!(dedup.check_at("a", now), Check::New);
+    assert_eq!(dedup.check_at("a", now), Check::InFlight);
+    // Forwarding failed so the redelivery is forwarded
+    dedup.abandon("a");
+    assert_eq!(dedup.check_at("a", now), Check::New);
+    dedup.mark_delivered_at("a", now);
+    assert_eq!(dedup.check_at("a", now), Check::Delivered);
+}
+
+#[test]
+fn dedup_file_backend() {
+    let path = std::env::temp_dir().join(format!("magit-dedup-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let now = SystemTime::now();
+
+    let dedup = Dedup::with_backend(
+        8,
+        Duration::from_secs(60),
+        Box::new(FileBackend::open(&path).unwrap()),
+    )
+    .unwrap();
+    assert!(forward(&dedup, "a", now));
+    assert!(forward(&dedup, "b", now - Duration::from_secs(120)));
+    // Never marked as delivered so it is not persisted
+    assert_eq!(dedup.check_at("c", now), Check::New);
+    drop(dedup);
+
+    let dedup = Dedup::with_backend(
+        8,
+        Duration::from_secs(60),
+        Box::new(FileBackend::open(&path).unwrap()),
+    )
+    .unwrap();
+    assert!(!forward(&dedup, "a", now));
+    // Outside the window so it was dropped when loading
+    assert!(forward(&dedup, "b", now));
+    assert!(forward(&dedup, "c", now));
+
+    fs::remove_file(&path).unwrap();
+}
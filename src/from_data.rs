@@ -1,5 +1,7 @@
-use gitty_hub::api::{EventKind, GitHubEvent};
-use hmac::{Hmac, Mac, NewMac};
+use gitty_hub::{
+    api::WebhookDelivery,
+    webhooks::{delivery, verify},
+};
 use rocket::{
     data,
     data::FromData,
@@ -8,16 +10,19 @@ use rocket::{
     request::Request,
     Data,
 };
-use sha2::Sha256;
 use tokio::io::AsyncReadExt;
 
+use crate::Store;
+
 // TODO: accept: 'application/vnd.github.v3+json'
-pub const X_GITHUB_EVENT: &str = "x-github-event";
-pub const X_HUB_SIGNATURE: &str = "x-hub-signature-256";
+pub const X_GITHUB_EVENT: &str = delivery::X_GITHUB_EVENT;
+pub const X_GITHUB_DELIVERY: &str = delivery::X_GITHUB_DELIVERY;
+pub const X_HUB_SIGNATURE: &str = verify::X_HUB_SIGNATURE_256;
+pub const X_HUB_SIGNATURE_SHA1: &str = verify::X_HUB_SIGNATURE;
 pub const CONTENT_LEN: &str = "content-length";
 pub const CONTENT_TYPE: &str = "content-type";
 
-pub struct GithubHookEvent<'a>(pub(crate) GitHubEvent<'a>);
+pub struct GithubHookEvent<'a>(pub(crate) WebhookDelivery<'a>);
 
 #[rocket::async_trait]
 impl<'r> FromData<'r> for GithubHookEvent<'r> {
@@ -27,7 +32,13 @@ impl<'r> FromData<'r> for GithubHookEvent<'r> {
         request: &'r Request<'_>,
         data: Data,
     ) -> data::Outcome<GithubHookEvent<'r>, Self::Error> {
-        let keys = request.headers().get(X_HUB_SIGNATURE).collect::<Vec<_>>();
+        let mut algorithm = verify::Algorithm::Sha256;
+        let mut keys = request.headers().get(X_HUB_SIGNATURE).collect::<Vec<_>>();
+        if keys.is_empty() {
+            // Fall back to the legacy sha1 signature
+            algorithm = verify::Algorithm::Sha1;
+            keys = request.headers().get(X_HUB_SIGNATURE_SHA1).collect();
+        }
         let content_len = request.headers().get(CONTENT_LEN).collect::<Vec<_>>();
         let content_type = request.headers().get(CONTENT_TYPE).collect::<Vec<_>>();
         if content_type.len() != 1 {
@@ -67,20 +78,22 @@ impl<'r> FromData<'r> for GithubHookEvent<'r> {
             ));
         }
 
-        let secret = match std::env::var("__GITHUB_WEBHOOK_SECRET") {
-            Ok(s) => s,
-            Err(_) => {
-                return Outcome::Failure((
-                    Status::InternalServerError,
-                    "No secret key found".to_owned(),
-                ));
-            }
+        let secrets = match request.rocket().state::<Store>() {
+            Some(store) => store.config.secrets().collect::<Vec<_>>(),
+            None => vec![],
         };
+        if secrets.is_empty() {
+            return Outcome::Failure((
+                Status::InternalServerError,
+                "No secret key found".to_owned(),
+            ));
+        }
 
-        if !validate(secret.as_str(), signature, &string) {
+        let verifier = verify::WebhookVerifier::with_secrets(secrets);
+        if let Err(err) = verifier.verify(algorithm, signature, &string) {
             return Outcome::Failure((
                 Status::BadRequest,
-                "Validation failed".to_owned(),
+                format!("Validation failed: {}", err),
             ));
         }
 
@@ -102,147 +115,10 @@ impl<'r> FromData<'r> for GithubHookEvent<'r> {
         // We store `string` in request-local cache for long-lived borrows.
         let body = rocket::request::local_cache!(request, decoded);
 
-        Outcome::Success(GithubHookEvent(match keys[0].into() {
-            EventKind::CheckSuite => {
-                GitHubEvent::CheckSuite(
-                    match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                        Ok(ev) => ev,
-                        Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                    },
-                )
-            }
-            EventKind::CheckRun => GitHubEvent::CheckRun(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::CommitComment => GitHubEvent::CommitComment(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Create => GitHubEvent::Create(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Delete => GitHubEvent::Delete(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Installation => GitHubEvent::Installation(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Issues => GitHubEvent::Issue(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::IssueComment => GitHubEvent::IssueComment(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Milestone => GitHubEvent::Milestone(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Ping => GitHubEvent::Ping(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::PullRequest => GitHubEvent::PullRequest(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::PullRequestReview => GitHubEvent::PullRequestReview(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::PullRequestReviewComment => GitHubEvent::PullRequestReviewComment(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => {
-                        return Outcome::Failure((Status::BadRequest, err));
-                    }
-                },
-            ),
-            EventKind::Push => GitHubEvent::Push(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Release => GitHubEvent::Release(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Star => GitHubEvent::Star(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Status => GitHubEvent::Status(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            EventKind::Watch => GitHubEvent::Watch(
-                match serde_json::from_str(body).map_err(|e| e.to_string()) {
-                    Ok(ev) => ev,
-                    Err(err) => return Outcome::Failure((Status::BadRequest, err)),
-                },
-            ),
-            ev => {
-                return Outcome::Failure((
-                    Status::BadRequest,
-                    format!("Found unknown event `{}`", ev),
-                ));
-            }
-        }))
-    }
-}
-
-pub fn validate<B: AsRef<[u8]>>(secret: B, signature: B, message: B) -> bool {
-    let signature = &signature.as_ref()[7..];
-
-    let mut hmac = Hmac::<Sha256>::new_from_slice(secret.as_ref())
-        .expect("failed to create Hmac digest");
-    hmac.update(message.as_ref());
-    let end = hmac.finalize();
-    let x = end.into_bytes();
-    let s = bytes_to_hex(x.as_slice());
-    s.eq(signature.as_ref())
-}
-
-pub fn bytes_to_hex(bytes: &[u8]) -> Vec<u8> {
-    const CHARS: &[u8] = b"0123456789abcdef";
-    let mut v = Vec::with_capacity(bytes.len() * 2);
-    for &byte in bytes {
-        v.push(CHARS[(byte >> 4) as usize]);
-        v.push(CHARS[(byte & 0xf) as usize]);
+        let header = |name: &str| request.headers().get_one(name);
+        match WebhookDelivery::from_headers(header, body) {
+            Ok(delivery) => Outcome::Success(GithubHookEvent(delivery)),
+            Err(err) => Outcome::Failure((Status::BadRequest, err.to_string())),
+        }
     }
-    v
 }
@@ -9,7 +9,10 @@ use ruma::RoomId;
 use serde::Deserialize;
 use tokio::sync::mpsc::Sender;
 
+use crate::dedup::{Dedup, DedupConfig};
+
 pub mod cmd_request;
+pub mod dedup;
 pub mod from_data;
 pub mod routes;
 pub mod strfmt;
@@ -39,6 +42,18 @@ pub struct GithubConfig {
     pub user_name: String,
     pub password: String,
     pub user_token: Option<String>,
+    /// The room new Q&A discussions are announced in, if not set they are sent to the
+    /// repository's room.
+    #[serde(default)]
+    pub support_room: Option<RoomId>,
+    /// The room security alerts are sent to, alerts are dropped if this is not set so
+    /// they never end up in a repository's room. A repository being made public, gaining
+    /// a collaborator or being renamed is sent here as well as to the repository's room.
+    #[serde(default)]
+    pub security_room: Option<RoomId>,
+    /// How redelivered webhooks are recognized so they are only sent to Matrix once.
+    #[serde(default)]
+    pub dedup: DedupConfig,
     #[serde(flatten)]
     pub format_strings: BTreeMap<String, String>,
 }
@@ -46,14 +61,24 @@ pub struct GithubConfig {
 #[derive(Clone, Debug, Deserialize)]
 pub struct Config {
     pub secret_key: Option<String>,
+    /// Other secrets deliveries may be signed with, ie. the previous secret while the
+    /// hook's secret is being rotated.
+    #[serde(default)]
+    pub secret_keys: Vec<String>,
     pub github: GithubConfig,
 }
 
 impl Config {
+    /// Every secret a delivery's signature is checked against.
+    pub fn secrets(&self) -> impl Iterator<Item = &str> {
+        self.secret_key.iter().chain(&self.secret_keys).map(String::as_str)
+    }
+
     #[doc(hidden)]
     pub fn debug() -> Self {
         Self {
             secret_key: Some("test".to_owned()),
+            secret_keys: vec![],
             github: GithubConfig {
                 repos: vec![],
                 events: vec![],
@@ -61,6 +86,9 @@ impl Config {
                 user_name: "foobar.com".to_owned(),
                 password: "foobar.com".to_owned(),
                 user_token: None,
+                support_room: None,
+                security_room: None,
+                dedup: DedupConfig::default(),
                 format_strings: BTreeMap::default(),
             },
         }
@@ -88,6 +116,7 @@ where
 pub struct Store {
     pub config: Arc<Config>,
     pub to_matrix: Sender<(RoomId, String)>,
+    pub dedup: Dedup,
 }
 
 pub fn parse_config() -> (Figment, Config) {
@@ -104,10 +133,5 @@ pub fn parse_config() -> (Figment, Config) {
     let config: Config =
         raw_config.extract().expect("it looks like your config is invalid");
 
-    std::env::set_var(
-        "__GITHUB_WEBHOOK_SECRET",
-        &config.secret_key.as_deref().unwrap_or(""),
-    );
-
     (raw_config, config)
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use gitty_hub::GithubClient;
 use magit::{
     cmd_request::{issue_request, octocat_request, Command, GithubBot},
+    dedup::Dedup,
     parse_config, routes, RepoRoomMap, Store,
 };
 use matrix_sdk::{
@@ -77,10 +78,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     )
     .await?;
 
+    let dedup = Dedup::from_config(&config.github.dedup)?;
+
     let config = Arc::new(config);
     let config_cp = Arc::clone(&config);
 
-    let store = Store { config, to_matrix };
+    let store = Store { config, to_matrix, dedup };
 
     let matrix_client = client.clone();
     let github_client = GithubClient::new(store.config.github.user_token.clone())?;
@@ -13,13 +13,26 @@ use tracing::{debug, warn};
 use crate::{
     api::{
         webhooks::{
+            code_scanning_alert::CodeScanningAlertEvent,
+            dependabot_alert::DependabotAlertEvent,
+            deployment_status::DeploymentStatusEvent,
+            discussion::{DiscussionAction, DiscussionEvent},
             issue::IssueEvent,
+            member::{MemberAction, MemberEvent},
+            ping::{ContentType, PingEvent},
+            public::PublicEvent,
             pull::{PullRequestAction, PullRequestEvent},
             push::PushEvent,
-            GitHubEvent,
+            repository::{RepositoryAction, RepositoryEvent},
+            repository_vulnerability_alert::RepositoryVulnerabilityAlertEvent,
+            secret_scanning_alert::SecretScanningAlertEvent,
+            workflow_job::{WorkflowJobAction, WorkflowJobEvent},
+            workflow_run::{WorkflowRunAction, WorkflowRunEvent},
+            GitHubEvent, WebhookPayload,
         },
-        IssueState,
+        ConclusionStatus, DeploymentState, EventKind, IssueState,
     },
+    dedup::Check,
     from_data::GithubHookEvent,
     str_fmt, RepoRoomMap, Store,
 };
@@ -64,49 +77,161 @@ pub async fn index(
     to_matrix: &State<Store>,
 ) -> ResponseResult<Status> {
     let store: &Store = to_matrix;
-    if !store.config.github.events.contains(&event.0.as_kind()) {
-        debug!("found {:?}, not one of our hooks", event.0.as_kind());
+    let delivery = event.0;
+    if !store.config.github.events.contains(&delivery.event.as_kind()) {
+        debug!(
+            "found {:?} in delivery {:?}, not one of our hooks",
+            delivery.event.as_kind(),
+            delivery.id
+        );
         return Ok(Status::NoContent);
     }
-    match event.0 {
+    // Github redelivers on timeouts and when asked to, acknowledge these so it stops
+    if let Some(id) = delivery.id.as_deref() {
+        match store.dedup.check(id) {
+            Check::New => {}
+            Check::Delivered => {
+                debug!("already forwarded delivery {}", id);
+                return Ok(Status::Ok);
+            }
+            // Not acknowledged, if forwarding the first one fails this can be redelivered
+            Check::InFlight => {
+                debug!("delivery {} is already being forwarded", id);
+                return Ok(Status::Conflict);
+            }
+        }
+    }
+
+    let res = dispatch(delivery.event, store).await;
+    if let Some(id) = delivery.id.as_deref() {
+        match res {
+            Ok(()) => store.dedup.mark_delivered(id),
+            // Let a redelivery of this try again
+            Err(_) => store.dedup.abandon(id),
+        }
+    }
+    res.map(|()| Status::Ok)
+}
+
+async fn dispatch(event: GitHubEvent<'_>, store: &Store) -> ResponseResult<()> {
+    debug!(
+        "{} {} in {} from {}",
+        event.as_kind(),
+        event.action().unwrap_or_default(),
+        event.repository().map_or("<no repository>", |repo| &*repo.full_name),
+        event.sender().map_or("<unknown>", |user| &*user.login),
+    );
+
+    match event {
         GitHubEvent::CheckRun(_) => {}
         GitHubEvent::CheckSuite(_) => {}
+        GitHubEvent::CodeScanningAlert(alert) => {
+            handle_code_scanning_alert(alert, store).await?
+        }
         GitHubEvent::CommitComment(_) => {}
+        GitHubEvent::ContentReference(_) => {}
         GitHubEvent::Create(_) => {}
         GitHubEvent::Delete(_) => {}
+        GitHubEvent::DependabotAlert(alert) => {
+            handle_dependabot_alert(alert, store).await?
+        }
+        GitHubEvent::DeployKey(_) => {}
+        GitHubEvent::Deployment(_) => {}
+        GitHubEvent::DeploymentReview(_) => {}
+        GitHubEvent::DeploymentStatus(status) => {
+            handle_deployment_status(status, store).await?
+        }
+        GitHubEvent::Discussion(discussion) => {
+            handle_discussion(discussion, store).await?
+        }
+        GitHubEvent::DiscussionComment(_) => {}
+        GitHubEvent::Fork(_) => {}
+        GitHubEvent::Gollum(_) => {}
         GitHubEvent::Installation(_) => {}
         GitHubEvent::Issue(issue) => handle_issue(issue, store).await?,
         GitHubEvent::IssueComment(_) => {}
+        GitHubEvent::Label(_) => {}
+        GitHubEvent::Member(member) => handle_member(member, store).await?,
+        GitHubEvent::Membership(_) => {}
         GitHubEvent::Milestone(_) => {}
-        GitHubEvent::Ping(ping) => {
-            if let Some(zen) = ping["zen"].as_str() {
-                for RepoRoomMap { room, .. } in &store.config.github.repos {
-                    store.to_matrix.send((room.clone(), zen.to_string())).await?;
-                }
-            }
-        }
+        GitHubEvent::OrgBlock(_) => {}
+        GitHubEvent::Organization(_) => {}
+        GitHubEvent::PageBuild(_) => {}
+        GitHubEvent::Ping(ping) => handle_ping(ping, store).await?,
+        GitHubEvent::Project(_) => {}
+        GitHubEvent::ProjectCard(_) => {}
+        GitHubEvent::ProjectColumn(_) => {}
+        GitHubEvent::Public(public) => handle_public(public, store).await?,
         GitHubEvent::PullRequest(pull) => handle_pull_request(pull, store).await?,
         GitHubEvent::PullRequestReview(_) => {}
         GitHubEvent::PullRequestReviewComment(_) => {}
         GitHubEvent::Push(push) => handle_push(push, store).await?,
+        GitHubEvent::RegistryPackage(_) => {}
         GitHubEvent::Release(_) => {}
+        GitHubEvent::Repository(repo) => handle_repository(repo, store).await?,
+        GitHubEvent::RepositoryDispatch(_) => {}
+        GitHubEvent::RepositoryVulnerabilityAlert(alert) => {
+            handle_vulnerability_alert(alert, store).await?
+        }
+        GitHubEvent::SecretScanningAlert(alert) => {
+            handle_secret_scanning_alert(alert, store).await?
+        }
         GitHubEvent::Star(star) => {
-            if let Some(room) = store
-                .config
-                .github
-                .repos
-                .iter()
-                .find(|map| map.repo_name.as_full_name() == star.repository.full_name)
-                .map(|r| r.room.clone())
-            {
+            if let Some(room) = repo_room(store, &star) {
                 store.to_matrix.send((room, star.sender.login.to_string())).await?;
             }
         }
         GitHubEvent::Status(_) => {}
+        GitHubEvent::Team(_) => {}
+        GitHubEvent::TeamAdd(_) => {}
         GitHubEvent::Watch(_) => {}
+        GitHubEvent::WorkflowDispatch(_) => {}
+        GitHubEvent::WorkflowJob(job) => handle_workflow_job(job, store).await?,
+        GitHubEvent::WorkflowRun(run) => handle_workflow_run(run, store).await?,
+        GitHubEvent::Unknown { kind, .. } => debug!("no handler for {} events", kind),
     };
 
-    Ok(Status::Ok)
+    Ok(())
+}
+
+/// Sends the zen of a newly created hook along with a warning if the hook is not set up
+/// to deliver what the config expects.
+async fn handle_ping(ping: PingEvent<'_>, store: &Store) -> ResponseResult<()> {
+    let mut msg = ping.zen.to_string();
+
+    // Github always sends the ping, it is not something a hook subscribes to
+    let missing = store
+        .config
+        .github
+        .events
+        .iter()
+        .filter(|kind| **kind != EventKind::Ping && !ping.hook.subscribes_to(kind))
+        .map(|kind| kind.to_string())
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        warn!("hook {} is missing events {:?}", ping.hook.id, missing);
+        msg.push_str(&format!(
+            "\nWarning: this hook is not subscribed to the {} event{}",
+            missing.join(", "),
+            if missing.len() > 1 { "s" } else { "" },
+        ));
+    }
+    if !matches!(ping.hook.config.content_type, ContentType::Json) {
+        warn!("hook {} sends {} payloads", ping.hook.id, ping.hook.config.content_type);
+        msg.push_str(&format!(
+            "\nWarning: this hook sends `{}` payloads, only `json` is supported",
+            ping.hook.config.content_type,
+        ));
+    }
+
+    // Organization hooks have no repository, let every room know
+    let repo_name = ping.repository.as_ref().map(|repo| &*repo.full_name);
+    for RepoRoomMap { repo_name: name, room } in &store.config.github.repos {
+        if repo_name.is_none() || repo_name == Some(name.as_full_name().as_str()) {
+            store.to_matrix.send((room.clone(), msg.clone())).await?;
+        }
+    }
+    Ok(())
 }
 
 async fn handle_issue(issue: IssueEvent<'_>, store: &Store) -> ResponseResult<()> {
@@ -119,15 +244,16 @@ async fn handle_issue(issue: IssueEvent<'_>, store: &Store) -> ResponseResult<()
     let body;
     let state;
     ready_to_fmt! {
-        repo_name = issue.repository.full_name;
-        username = issue.issue.user.login;
+        repo_name = &*issue.repository.full_name;
+        username = &*issue.issue.user.login;
         ref issue_number = issue.issue.number.to_string();
         ref linked_pr = issue.issue.pull_request
+            .as_ref()
             .map(|pr| format!("a [linked pull request]({})", pr.html_url))
             .unwrap_or_else(|| "no linked pull request".to_owned());
         ref issue_url = issue.issue.html_url.to_string();
-        title = issue.issue.title;
-        body = issue.issue.body;
+        title = &*issue.issue.title;
+        body = &*issue.issue.body;
         state = match issue.issue.state {
             IssueState::Open => "opened",
             IssueState::Closed => "closed",
@@ -137,13 +263,7 @@ async fn handle_issue(issue: IssueEvent<'_>, store: &Store) -> ResponseResult<()
     }
 
     let fmt_str = store.config.github.format_strings.get("issues");
-    let room = store
-        .config
-        .github
-        .repos
-        .iter()
-        .find(|map| map.repo_name.as_full_name() == repo_name)
-        .map(|r| r.room.clone());
+    let room = repo_room(store, &issue);
     match (fmt_str, room) {
         (Some(fmt_str), Some(room)) => {
             store
@@ -209,10 +329,10 @@ async fn handle_pull_request(
     let title;
     let body;
     ready_to_fmt! {
-        repo_name = pull.repository.full_name;
-        username = pull.pull_request.user.login;
-        current = pull.pull_request.head.ref_;
-        base = pull.pull_request.base.ref_;
+        repo_name = &*pull.repository.full_name;
+        username = &*pull.pull_request.user.login;
+        current = &*pull.pull_request.head.ref_;
+        base = &*pull.pull_request.base.ref_;
         ref additions = pull.pull_request.additions.to_string();
         ref deletions = pull.pull_request.deletions.to_string();
         ref changed_files = pull.pull_request.changed_files.to_string();
@@ -224,8 +344,8 @@ async fn handle_pull_request(
             (false, false) => "in rough shape, neither rebase-able or merge-able",
         };
         ref pull_url = pull.pull_request.html_url.to_string();
-        title = pull.pull_request.title;
-        body = pull.pull_request.body;
+        title = &*pull.pull_request.title;
+        body = &*pull.pull_request.body;
     }
 
     let action = match pull.action {
@@ -233,6 +353,7 @@ async fn handle_pull_request(
             let assignee = pull
                 .pull_request
                 .assignee
+                .as_ref()
                 .map(|a| a.login.to_string())
                 .unwrap_or_else(|| "<unknown>".to_owned());
             format!("PR was assigned to {}", assignee)
@@ -249,7 +370,14 @@ async fn handle_pull_request(
             }
         }
         PullRequestAction::ConvertToDraft => "PR was converted to a draft".to_owned(),
-        PullRequestAction::Edited => "PR has been edited".to_owned(),
+        PullRequestAction::Edited => {
+            let diff = pull.diff();
+            if diff.is_empty() {
+                "PR has been edited".to_owned()
+            } else {
+                format!("PR has been edited, {}", diff)
+            }
+        }
         PullRequestAction::Labeled => "PR has been labeled".to_owned(),
         PullRequestAction::Locked => "PR has been locked".to_owned(),
         PullRequestAction::Opened => "PR has been opened".to_owned(),
@@ -268,13 +396,7 @@ async fn handle_pull_request(
     let action = &action;
 
     let fmt_str = store.config.github.format_strings.get("pull_request");
-    let room = store
-        .config
-        .github
-        .repos
-        .iter()
-        .find(|map| map.repo_name.as_full_name() == repo_name)
-        .map(|r| r.room.clone());
+    let room = repo_room(store, &pull);
     match (fmt_str, room) {
         (Some(fmt_str), Some(room)) => {
             store
@@ -344,22 +466,16 @@ async fn handle_push(push: PushEvent<'_>, store: &Store) -> ResponseResult<()> {
     let plural;
     let branch;
     ready_to_fmt! {
-        username = push.sender.login;
-        repo_name = push.repository.full_name;
-        commits_url = push.compare;
+        username = &*push.sender.login;
+        repo_name = &*push.repository.full_name;
+        commits_url = &*push.compare;
         ref commits_count = push.commits.len().to_string();
         plural = if push.commits.len() > 1 { "s" } else { "" };
         ref branch = push.ref_.split('/').last().map(|s| s.to_string()).unwrap_or_default();
     }
 
     let fmt_str = store.config.github.format_strings.get("push");
-    let room = store
-        .config
-        .github
-        .repos
-        .iter()
-        .find(|map| map.repo_name.as_full_name() == repo_name)
-        .map(|r| r.room.clone());
+    let room = repo_room(store, &push);
     match (fmt_str, room) {
         (Some(fmt_str), Some(room)) => {
             store
@@ -395,6 +511,617 @@ async fn handle_push(push: PushEvent<'_>, store: &Store) -> ResponseResult<()> {
     Ok(())
 }
 
+async fn handle_deployment_status(
+    status: DeploymentStatusEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    let username;
+    let repo_name;
+    let environment;
+    let git_ref;
+    let state;
+    let description;
+    let environment_url;
+    ready_to_fmt! {
+        username = &*status.sender.login;
+        repo_name = &*status.repository.full_name;
+        environment = &*status.deployment.environment;
+        git_ref = status.deployment.ref_.as_deref().unwrap_or("<unknown>");
+        state = match status.deployment_status.state {
+            DeploymentState::Pending => "is pending",
+            DeploymentState::Queued => "was queued",
+            DeploymentState::InProgress => "is in progress",
+            DeploymentState::Success => "succeeded",
+            DeploymentState::Failure => "failed",
+            DeploymentState::Error => "errored",
+            DeploymentState::Inactive => "is inactive",
+            _ => "<new unknown variant>",
+        };
+        description = &*status.deployment_status.description;
+        environment_url = status.deployment_status.environment_url.as_deref().unwrap_or_default();
+    }
+
+    let fmt_str = store.config.github.format_strings.get("deployment_status");
+    let room = repo_room(store, &status);
+    match (fmt_str, room) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    str_fmt!(
+                        fmt_str,
+                        repo_name,
+                        username,
+                        environment,
+                        git_ref,
+                        state,
+                        description,
+                        environment_url
+                    ),
+                ))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        "[{}] deployment of {} to {} {}.\n{}\n{}",
+                        repo_name,
+                        git_ref,
+                        environment,
+                        state,
+                        description,
+                        environment_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Announces new questions, ie. discussions in an answerable category like `Q&A`.
+async fn handle_discussion(
+    discussion: DiscussionEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    if !matches!(discussion.action, DiscussionAction::Created)
+        || !discussion.discussion.category.is_answerable
+    {
+        return Ok(());
+    }
+
+    let repo_name;
+    let username;
+    let category;
+    let title;
+    let discussion_url;
+    ready_to_fmt! {
+        repo_name = &*discussion.repository.full_name;
+        username = &*discussion.discussion.user.login;
+        category = &*discussion.discussion.category.name;
+        title = &*discussion.discussion.title;
+        ref discussion_url = discussion.discussion.html_url.to_string();
+    }
+
+    let fmt_str = store.config.github.format_strings.get("discussion");
+    let room = store
+        .config
+        .github
+        .support_room
+        .clone()
+        .or_else(|| repo_room(store, &discussion));
+    match (fmt_str, room) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    str_fmt!(
+                        fmt_str,
+                        repo_name,
+                        username,
+                        category,
+                        title,
+                        discussion_url
+                    ),
+                ))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        "[{}] {} asked in {}: {}\n[Check out the discussion!]({})",
+                        repo_name, username, category, title, discussion_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The room of the repository `event` happened in, `None` if the event has no repository
+/// or the repository is not mapped to a room.
+fn repo_room(store: &Store, event: &impl WebhookPayload) -> Option<RoomId> {
+    let repo_name = &*event.repository()?.full_name;
+    store
+        .config
+        .github
+        .repos
+        .iter()
+        .find(|map| map.repo_name.as_full_name() == repo_name)
+        .map(|r| r.room.clone())
+}
+
+/// The rooms repository administration events go to, the repository's own room and the
+/// `security_room` so a repo going public or gaining a collaborator is not missed.
+fn admin_rooms(store: &Store, event: &impl WebhookPayload) -> Vec<RoomId> {
+    let mut rooms: Vec<RoomId> = repo_room(store, event).into_iter().collect();
+    if let Some(room) = &store.config.github.security_room {
+        if !rooms.contains(room) {
+            rooms.push(room.clone());
+        }
+    }
+    rooms
+}
+
+/// A private repository was made public.
+async fn handle_public(public: PublicEvent<'_>, store: &Store) -> ResponseResult<()> {
+    let repo_name;
+    let username;
+    ready_to_fmt! {
+        repo_name = &*public.repository.full_name;
+        username = &*public.sender.login;
+    }
+
+    let msg = match store.config.github.format_strings.get("public") {
+        Some(fmt_str) => str_fmt!(fmt_str, repo_name, username),
+        None => format!("[{}] {} made the repository public", repo_name, username),
+    };
+    for room in admin_rooms(store, &public) {
+        store.to_matrix.send((room, msg.clone())).await?;
+    }
+    Ok(())
+}
+
+/// Only new collaborators are sent.
+async fn handle_member(member: MemberEvent<'_>, store: &Store) -> ResponseResult<()> {
+    if !matches!(member.action, MemberAction::Added) {
+        return Ok(());
+    }
+
+    let repo_name;
+    let username;
+    let collaborator;
+    let permission;
+    ready_to_fmt! {
+        repo_name = &*member.repository.full_name;
+        username = &*member.sender.login;
+        collaborator = &*member.member.login;
+        permission = member
+            .changes
+            .as_ref()
+            .and_then(|changes| changes.permission.as_ref())
+            .and_then(|permission| permission.to.as_deref())
+            .unwrap_or("default");
+    }
+
+    let msg = match store.config.github.format_strings.get("member") {
+        Some(fmt_str) => str_fmt!(fmt_str, repo_name, username, collaborator, permission),
+        None => format!(
+            "[{}] {} added {} as a collaborator with {} permission",
+            repo_name, username, collaborator, permission
+        ),
+    };
+    for room in admin_rooms(store, &member) {
+        store.to_matrix.send((room, msg.clone())).await?;
+    }
+    Ok(())
+}
+
+/// Only renames are sent, a repository being made public is sent from its `public`
+/// event since GitHub delivers both.
+async fn handle_repository(
+    repo: RepositoryEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    let repo_name;
+    let username;
+    let change;
+    ready_to_fmt! {
+        repo_name = &*repo.repository.full_name;
+        username = &*repo.sender.login;
+        ref change = match repo.action {
+            RepositoryAction::Renamed => format!(
+                "renamed the repository from {}",
+                repo.changes
+                    .as_ref()
+                    .and_then(|changes| changes.repository.as_ref())
+                    .and_then(|changes| changes.name.as_ref())
+                    .map_or("<unknown>", |name| &*name.from)
+            ),
+            _ => return Ok(()),
+        };
+    }
+
+    let msg = match store.config.github.format_strings.get("repository") {
+        Some(fmt_str) => str_fmt!(fmt_str, repo_name, username, change),
+        None => format!("[{}] {} {}", repo_name, username, change),
+    };
+    for room in admin_rooms(store, &repo) {
+        store.to_matrix.send((room, msg.clone())).await?;
+    }
+    Ok(())
+}
+
+/// Only runs that did not succeed are sent, everything else is noise.
+async fn handle_workflow_run(
+    run: WorkflowRunEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    if !matches!(run.action, WorkflowRunAction::Completed)
+        || !matches!(
+            run.workflow_run.conclusion,
+            Some(ConclusionStatus::Failure | ConclusionStatus::TimeOut)
+        )
+    {
+        return Ok(());
+    }
+
+    let username;
+    let repo_name;
+    let workflow;
+    let branch;
+    let run_number;
+    let conclusion;
+    let run_url;
+    ready_to_fmt! {
+        username = &*run.workflow_run.actor.as_ref().unwrap_or(&run.sender).login;
+        repo_name = &*run.repository.full_name;
+        workflow = &*run.workflow.name;
+        branch = &*run.workflow_run.head_branch;
+        ref run_number = run.workflow_run.run_number.to_string();
+        conclusion = match run.workflow_run.conclusion {
+            Some(ConclusionStatus::TimeOut) => "timed out",
+            _ => "failed",
+        };
+        run_url = &*run.workflow_run.html_url;
+    }
+
+    let fmt_str = store.config.github.format_strings.get("workflow_run");
+    let room = repo_room(store, &run);
+    match (fmt_str, room) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    str_fmt!(
+                        fmt_str, repo_name, username, workflow, branch, run_number,
+                        conclusion, run_url
+                    ),
+                ))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        r#"[{}] {} run #{} {} on {} (triggered by {}).
+[Check out the run!]({})"#,
+                        repo_name,
+                        workflow,
+                        run_number,
+                        conclusion,
+                        branch,
+                        username,
+                        run_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Only jobs that failed are sent, along with the step that failed.
+async fn handle_workflow_job(
+    job: WorkflowJobEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    if !matches!(job.action, WorkflowJobAction::Completed)
+        || !matches!(job.workflow_job.conclusion, Some(ConclusionStatus::Failure))
+    {
+        return Ok(());
+    }
+
+    let repo_name;
+    let job_name;
+    let step;
+    let sha;
+    let job_url;
+    ready_to_fmt! {
+        repo_name = &*job.repository.full_name;
+        job_name = &*job.workflow_job.name;
+        step = job.workflow_job.failed_step().map(|s| &*s.name).unwrap_or("<unknown>");
+        sha = job.workflow_job.head_sha.get(..7).unwrap_or_default();
+        ref job_url = job.workflow_job.html_url.to_string();
+    }
+
+    let fmt_str = store.config.github.format_strings.get("workflow_job");
+    let room = repo_room(store, &job);
+    match (fmt_str, room) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((room, str_fmt!(fmt_str, repo_name, job_name, step, sha, job_url)))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        r#"[{}] job {} failed at step `{}` on {}.
+[Check out the job!]({})"#,
+                        repo_name, job_name, step, sha, job_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The room security alerts go to, alerts are never sent to a repository's room.
+fn security_room(store: &Store, kind: &str) -> Option<RoomId> {
+    let room = store.config.github.security_room.clone();
+    if room.is_none() {
+        warn!("dropped {} event, `security_room` is not set", kind);
+    }
+    room
+}
+
+async fn handle_code_scanning_alert(
+    alert: CodeScanningAlertEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    let repo_name;
+    let action;
+    let number;
+    let rule;
+    let severity;
+    let location;
+    let alert_url;
+    ready_to_fmt! {
+        repo_name = &*alert.repository.full_name;
+        ref action = alert.action.as_ref().replace('_', " ");
+        ref number = alert.alert.number.to_string();
+        rule = &*alert.alert.rule.description;
+        severity = alert
+            .alert
+            .rule
+            .security_severity_level
+            .as_ref()
+            .map(AsRef::as_ref)
+            .or_else(|| alert.alert.rule.severity.as_ref().map(AsRef::as_ref))
+            .unwrap_or("unknown");
+        ref location = alert
+            .alert
+            .most_recent_instance
+            .as_ref()
+            .and_then(|inst| inst.location.as_ref())
+            .map(|loc| format!("{}:{}", loc.path, loc.start_line))
+            .unwrap_or_default();
+        ref alert_url = alert.alert.html_url.to_string();
+    }
+
+    let fmt_str = store.config.github.format_strings.get("code_scanning_alert");
+    match (fmt_str, security_room(store, "code_scanning_alert")) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    str_fmt!(
+                        fmt_str, repo_name, action, number, rule, severity, location,
+                        alert_url
+                    ),
+                ))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        r#"[{}] code scanning alert #{} {}: {} ({}) {}
+[Check out the alert!]({})"#,
+                        repo_name, number, action, rule, severity, location, alert_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_secret_scanning_alert(
+    alert: SecretScanningAlertEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    let repo_name;
+    let action;
+    let number;
+    let secret_type;
+    let alert_url;
+    ready_to_fmt! {
+        repo_name = &*alert.repository.full_name;
+        ref action = alert.action.as_ref().replace('_', " ");
+        ref number = alert.alert.number.to_string();
+        secret_type = alert
+            .alert
+            .secret_type_display_name
+            .as_deref()
+            .unwrap_or(&alert.alert.secret_type);
+        ref alert_url = alert.alert.html_url.to_string();
+    }
+
+    let fmt_str = store.config.github.format_strings.get("secret_scanning_alert");
+    match (fmt_str, security_room(store, "secret_scanning_alert")) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    str_fmt!(fmt_str, repo_name, action, number, secret_type, alert_url),
+                ))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        r#"[{}] secret scanning alert #{} {}: {}
+[Check out the alert!]({})"#,
+                        repo_name, number, action, secret_type, alert_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_dependabot_alert(
+    alert: DependabotAlertEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    let repo_name;
+    let action;
+    let number;
+    let package;
+    let severity;
+    let summary;
+    let alert_url;
+    ready_to_fmt! {
+        repo_name = &*alert.repository.full_name;
+        ref action = alert.action.as_ref().replace('_', " ");
+        ref number = alert.alert.number.to_string();
+        package = &*alert.alert.dependency.package.name;
+        severity = alert.alert.security_advisory.severity.as_ref();
+        summary = &*alert.alert.security_advisory.summary;
+        ref alert_url = alert.alert.html_url.to_string();
+    }
+
+    let fmt_str = store.config.github.format_strings.get("dependabot_alert");
+    match (fmt_str, security_room(store, "dependabot_alert")) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    str_fmt!(
+                        fmt_str, repo_name, action, number, package, severity, summary,
+                        alert_url
+                    ),
+                ))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        r#"[{}] dependabot alert #{} {}: {} ({}) {}
+[Check out the alert!]({})"#,
+                        repo_name, number, action, package, severity, summary, alert_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn handle_vulnerability_alert(
+    alert: RepositoryVulnerabilityAlertEvent<'_>,
+    store: &Store,
+) -> ResponseResult<()> {
+    let repo_name;
+    let action;
+    let package;
+    let range;
+    let severity;
+    let advisory_url;
+    ready_to_fmt! {
+        repo_name = &*alert.repository.full_name;
+        action = alert.action.as_ref();
+        package = &*alert.alert.affected_package_name;
+        range = &*alert.alert.affected_range;
+        severity = alert.alert.severity.as_ref();
+        advisory_url = &*alert.alert.external_reference;
+    }
+
+    let fmt_str =
+        store.config.github.format_strings.get("repository_vulnerability_alert");
+    match (fmt_str, security_room(store, "repository_vulnerability_alert")) {
+        (Some(fmt_str), Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    str_fmt!(
+                        fmt_str,
+                        repo_name,
+                        action,
+                        package,
+                        range,
+                        severity,
+                        advisory_url
+                    ),
+                ))
+                .await?;
+        }
+        (None, Some(room)) => {
+            store
+                .to_matrix
+                .send((
+                    room,
+                    format!(
+                        r#"[{}] vulnerability alert {}: {} {} ({})
+[Check out the advisory!]({})"#,
+                        repo_name, action, package, range, severity, advisory_url
+                    ),
+                ))
+                .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
 macro_rules! ready_to_fmt {
     (ref $name:ident = $init:expr; $($rest:tt)+) => {
         let s = $init;
@@ -1,19 +1,32 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
+use gitty_hub::webhooks::{
+    issue::IssueEvent,
+    pull::{PullRequestAction, PullRequestEvent},
+    verify,
+};
 use magit::{
-    from_data::{bytes_to_hex, CONTENT_LEN, X_GITHUB_EVENT, X_HUB_SIGNATURE},
+    dedup::{Dedup, FileBackend},
+    from_data::{
+        CONTENT_LEN, X_GITHUB_DELIVERY, X_GITHUB_EVENT, X_HUB_SIGNATURE,
+        X_HUB_SIGNATURE_SHA1,
+    },
     routes, Config, RepoName, RepoRoomMap, Store,
 };
 use rocket::{
     catchers,
     http::{ContentType, Header, Status},
-    local::asynchronous::Client,
+    local::asynchronous::{Client, LocalRequest, LocalResponse},
     routes, Build, Rocket,
 };
 use ruma::{room_id, RoomId};
 use tokio::sync::mpsc::{channel, Sender};
 
 fn app(to_matrix: Sender<(RoomId, String)>) -> Rocket<Build> {
+    app_with_dedup(to_matrix, Dedup::new(16, Duration::from_secs(60)))
+}
+
+fn app_with_dedup(to_matrix: Sender<(RoomId, String)>, dedup: Dedup) -> Rocket<Build> {
     let mut config = Config::debug();
     config.github.repos.push(RepoRoomMap {
         repo_name: RepoName {
@@ -33,6 +46,18 @@ fn app(to_matrix: Sender<(RoomId, String)>) -> Rocket<Build> {
     config.github.events.push("star".into());
     config.github.events.push("pull_request".into());
     config.github.events.push("issues".into());
+    config.github.events.push("deployment_status".into());
+    config.github.events.push("ping".into());
+    config.github.events.push("workflow_run".into());
+    config.github.events.push("workflow_job".into());
+    config.github.events.push("member".into());
+    config.github.events.push("public".into());
+    config.github.events.push("repository".into());
+    config.github.events.push("discussion".into());
+    config.github.events.push("code_scanning_alert".into());
+    config.github.events.push("dependabot_alert".into());
+    config.github.security_room = Some(room_id!("!security:aaa.com"));
+    config.secret_keys.push("old-secret".to_owned());
 
     std::env::set_var(
         "__GITHUB_WEBHOOK_SECRET",
@@ -40,7 +65,7 @@ fn app(to_matrix: Sender<(RoomId, String)>) -> Rocket<Build> {
     );
 
     let config = Arc::new(config);
-    let store = Store { config, to_matrix };
+    let store = Store { config, to_matrix, dedup };
 
     rocket::build()
         .manage(store)
@@ -49,15 +74,32 @@ fn app(to_matrix: Sender<(RoomId, String)>) -> Rocket<Build> {
 }
 
 fn make_signature(body: &str) -> String {
-    use hmac::{Mac, NewMac};
-
     let secret = std::env::var("__GITHUB_WEBHOOK_SECRET").unwrap();
-    let mut hmac = hmac::Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
-        .expect("failed to create Hmac digest");
-    hmac.update(body.as_bytes());
-    let end = hmac.finalize();
-    let x = end.into_bytes();
-    String::from_utf8_lossy(&bytes_to_hex(x.as_slice())).to_string()
+    verify::sign(verify::Algorithm::Sha256, secret, body)
+}
+
+/// A `kind` webhook request with `json` as the body and no signature.
+fn request<'c>(client: &'c Client, kind: &'static str, json: &str) -> LocalRequest<'c> {
+    client
+        .post("/")
+        .header(ContentType::JSON)
+        .header(Header::new(CONTENT_LEN, json.len().to_string()))
+        .header(Header::new(X_GITHUB_EVENT, kind))
+        .body(json)
+}
+
+/// A `kind` webhook request signed with the current secret.
+fn signed<'c>(client: &'c Client, kind: &'static str, json: &str) -> LocalRequest<'c> {
+    request(client, kind, json).header(Header::new(X_HUB_SIGNATURE, make_signature(json)))
+}
+
+/// Posts `json` as a signed `kind` event.
+async fn post_event<'c>(
+    client: &'c Client,
+    kind: &'static str,
+    json: &str,
+) -> LocalResponse<'c> {
+    signed(client, kind, json).dispatch().await
 }
 
 #[tokio::test]
@@ -66,56 +108,403 @@ async fn stars() {
     let json = include_str!("../gitty-hub/test_json/star.json");
 
     let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
-    let response = client
-        .post("/")
-        .header(ContentType::JSON)
-        .header(Header::new(CONTENT_LEN, json.len().to_string()))
-        .header(Header::new(X_GITHUB_EVENT, "star"))
-        .header(Header::new(X_HUB_SIGNATURE, format!("sha256={}", make_signature(json))))
-        .body(json)
-        .dispatch()
-        .await;
+    let response = post_event(&client, "star", json).await;
 
     assert_eq!(response.status(), Status::Ok);
     assert_eq!("DevinR528", from_gh.recv().await.unwrap().1);
 }
 
+#[tokio::test]
+async fn redelivery() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/star.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    for _ in 0..2 {
+        let response = signed(&client, "star", json)
+            .header(Header::new(
+                X_GITHUB_DELIVERY,
+                "72d3162e-cc78-11e3-81ab-4c9367dc0958",
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    assert_eq!("DevinR528", from_gh.recv().await.unwrap().1);
+    // The redelivery is acknowledged but not sent again
+    drop(client);
+    assert!(from_gh.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn redelivery_after_failure() {
+    let path =
+        std::env::temp_dir().join(format!("magit-redelivery-{}", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let json = include_str!("../gitty-hub/test_json/star.json");
+    let dedup = || {
+        let backend = FileBackend::open(&path).unwrap();
+        Dedup::with_backend(16, Duration::from_secs(60), Box::new(backend)).unwrap()
+    };
+
+    for (status, matrix_up) in [(Status::InternalServerError, false), (Status::Ok, true)]
+    {
+        let (to_matrix, mut from_gh) = channel(1024);
+        if !matrix_up {
+            from_gh.close();
+        }
+
+        let client = Client::debug(app_with_dedup(to_matrix, dedup()))
+            .await
+            .expect("valid rocket instance");
+        let response = signed(&client, "star", json)
+            .header(Header::new(
+                X_GITHUB_DELIVERY,
+                "72d3162e-cc78-11e3-81ab-4c9367dc0958",
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), status);
+        if matrix_up {
+            // The failed delivery was not recorded so the redelivery is sent
+            assert_eq!("DevinR528", from_gh.recv().await.unwrap().1);
+        }
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
+
 #[tokio::test]
 async fn pull_request() {
     let (to_matrix, mut from_gh) = channel(1024);
-    let json = include_str!("../gitty-hub/test_json/pull_request.json");
+    let json = PullRequestEvent::builder().author("bob").title("Fix the build").json();
 
     let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
-    let response = client
-        .post("/")
-        .header(ContentType::JSON)
-        .header(Header::new(CONTENT_LEN, json.len().to_string()))
-        .header(Header::new(X_GITHUB_EVENT, "pull_request"))
-        .header(Header::new(X_HUB_SIGNATURE, format!("sha256={}", make_signature(json))))
-        .body(json)
+    let response = post_event(&client, "pull_request", &json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (room, msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!aaa:aaa.com"));
+    assert!(msg.starts_with(
+        "[Codertocat/Hello-World] bob's PR has new activity: PR has been opened\n\
+         [Check out the pull request!](https://github.com/Codertocat/Hello-World/pull/2)\n\
+         Fix the build\n"
+    ));
+}
+
+#[tokio::test]
+async fn merged_pull_request() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = PullRequestEvent::builder()
+        .action(PullRequestAction::Closed)
+        .author("bob")
+        .merged(true)
+        .json();
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "pull_request", &json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (_, msg) = from_gh.recv().await.unwrap();
+    assert!(msg.starts_with(
+        "[Codertocat/Hello-World] bob's PR has new activity: PR was merged"
+    ));
+}
+
+#[tokio::test]
+async fn issue() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = IssueEvent::builder().author("bob").title("broken").json();
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "issues", &json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (room, msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!aaa:aaa.com"));
+    assert!(msg.starts_with(
+        "[Codertocat/Hello-World] bob opened issue #1\n\
+         [Check out the issue!](https://github.com/Codertocat/Hello-World/issues/1)\n\
+         broken\n"
+    ));
+}
+
+#[tokio::test]
+async fn deployment_status() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/deployment_status.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "deployment_status", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    assert!(from_gh
+        .recv()
+        .await
+        .unwrap()
+        .1
+        .contains("deployment of main to production succeeded"));
+}
+
+#[tokio::test]
+async fn ping() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/ping.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "ping", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let msg = from_gh.recv().await.unwrap().1;
+    assert!(msg.starts_with("Anything added dilutes everything else."));
+    // The hook only sends push and pull_request events
+    assert!(msg.contains(
+        "not subscribed to the star, issues, deployment_status, workflow_run, workflow_job, \
+         member, public, repository, discussion, code_scanning_alert, dependabot_alert \
+         events"
+    ));
+    assert!(!msg.contains("payloads"));
+}
+
+#[tokio::test]
+async fn workflow_run() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/workflow_run.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "workflow_run", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    assert!(from_gh
+        .recv()
+        .await
+        .unwrap()
+        .1
+        .contains("Build run #562 failed on changes (triggered by Codertocat)"));
+}
+
+#[tokio::test]
+async fn workflow_job() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/workflow_job.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "workflow_job", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    assert!(from_gh
+        .recv()
+        .await
+        .unwrap()
+        .1
+        .contains("job test failed at step `Run cargo test` on ec26c3e"));
+}
+
+#[tokio::test]
+async fn member() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/member.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "member", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (room, msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!aaa:aaa.com"));
+    assert_eq!(
+        msg,
+        "[Codertocat/Hello-World] Codertocat added octocat as a collaborator with write \
+         permission"
+    );
+    let (room, security_msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!security:aaa.com"));
+    assert_eq!(security_msg, msg);
+}
+
+#[tokio::test]
+async fn public() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/public.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "public", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (room, msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!aaa:aaa.com"));
+    assert_eq!(msg, "[Codertocat/Hello-World] Codertocat made the repository public");
+    let (room, security_msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!security:aaa.com"));
+    assert_eq!(security_msg, msg);
+}
+
+#[tokio::test]
+async fn repository() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/repository.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "repository", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (room, msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!aaa:aaa.com"));
+    assert_eq!(
+        msg,
+        "[Codertocat/Hello-World] Codertocat renamed the repository from Hello-Wrld"
+    );
+    let (room, security_msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!security:aaa.com"));
+    assert_eq!(security_msg, msg);
+}
+
+#[tokio::test]
+async fn repository_publicized() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/repository.json").replacen(
+        r#""action": "renamed""#,
+        r#""action": "publicized""#,
+        1,
+    );
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "repository", &json).await;
+
+    // The `public` event GitHub sends alongside this one is what gets posted
+    assert_eq!(response.status(), Status::Ok);
+    drop(response);
+    drop(client);
+    assert!(from_gh.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn discussion() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/discussion.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "discussion", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    assert!(from_gh.recv().await.unwrap().1.starts_with(
+        "[Codertocat/Hello-World] octocat asked in Q&A: How do I configure"
+    ));
+}
+
+#[tokio::test]
+async fn code_scanning_alert() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/code_scanning_alert.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "code_scanning_alert", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (room, msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!security:aaa.com"));
+    assert!(msg.starts_with(
+        "[Codertocat/Hello-World] code scanning alert #10 created: Unused variable, \
+         import, function or class (warning) src/index.js:12"
+    ));
+}
+
+#[tokio::test]
+async fn dependabot_alert() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/dependabot_alert.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "dependabot_alert", json).await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let (room, msg) = from_gh.recv().await.unwrap();
+    assert_eq!(room, room_id!("!security:aaa.com"));
+    assert!(msg.starts_with(
+        "[Codertocat/Hello-World] dependabot alert #2 created: smallvec (high) Buffer \
+         overflow in SmallVec::insert_many"
+    ));
+}
+
+#[tokio::test]
+async fn legacy_sha1_signature() {
+    let (to_matrix, mut from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/star.json");
+    let secret = std::env::var("__GITHUB_WEBHOOK_SECRET").unwrap_or_default();
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = request(&client, "star", json)
+        .header(Header::new(
+            X_HUB_SIGNATURE_SHA1,
+            verify::sign(verify::Algorithm::Sha1, secret, json),
+        ))
         .dispatch()
         .await;
 
     assert_eq!(response.status(), Status::Ok);
-    println!("{:?}", from_gh.recv().await.unwrap());
+    assert_eq!("DevinR528", from_gh.recv().await.unwrap().1);
 }
 
 #[tokio::test]
-async fn issue() {
+async fn rotated_secret() {
     let (to_matrix, mut from_gh) = channel(1024);
-    let json = include_str!("../gitty-hub/test_json/issue.json");
+    let json = include_str!("../gitty-hub/test_json/star.json");
 
     let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
-    let response = client
-        .post("/")
-        .header(ContentType::JSON)
-        .header(Header::new(CONTENT_LEN, json.len().to_string()))
-        .header(Header::new(X_GITHUB_EVENT, "issues"))
-        .header(Header::new(X_HUB_SIGNATURE, format!("sha256={}", make_signature(json))))
-        .body(json)
+    let response = request(&client, "star", json)
+        .header(Header::new(
+            X_HUB_SIGNATURE,
+            verify::sign(verify::Algorithm::Sha256, "old-secret", json),
+        ))
         .dispatch()
         .await;
 
     assert_eq!(response.status(), Status::Ok);
-    println!("{:?}", from_gh.recv().await.unwrap());
+    assert_eq!("DevinR528", from_gh.recv().await.unwrap().1);
+}
+
+#[tokio::test]
+async fn sha1_signature_in_sha256_header() {
+    let (to_matrix, _from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/star.json");
+    let secret = std::env::var("__GITHUB_WEBHOOK_SECRET").unwrap_or_default();
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = request(&client, "star", json)
+        .header(Header::new(
+            X_HUB_SIGNATURE,
+            verify::sign(verify::Algorithm::Sha1, secret, json),
+        ))
+        .dispatch()
+        .await;
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[tokio::test]
+async fn malformed_signature() {
+    let (to_matrix, _from_gh) = channel(1024);
+    let json = include_str!("../gitty-hub/test_json/star.json");
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = request(&client, "star", json)
+        .header(Header::new(X_HUB_SIGNATURE, "sha"))
+        .dispatch()
+        .await;
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[tokio::test]
+async fn unknown_event() {
+    let (to_matrix, _from_gh) = channel(1024);
+    let json = r#"{ "action": "created", "sender": { "login": "octocat" } }"#;
+
+    let client = Client::debug(app(to_matrix)).await.expect("valid rocket instance");
+    let response = post_event(&client, "brand_new_event", json).await;
+
+    // Not an error, just not an event this bot was configured for.
+    assert_eq!(response.status(), Status::NoContent);
 }